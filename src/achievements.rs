@@ -0,0 +1,85 @@
+use linera_sdk::base::{Amount, Owner, Timestamp};
+use crate::{Achievement, AchievementRecord, CowBreed, FeedingStatus, PlayerAchievement};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Achievements]
+/// Player milestones awarded by Root chain, some of them come with a one-time bonus from the treasury.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub async fn get_achievement_record(&self, owner: Owner) -> AchievementRecord {
+        self.achievements.get(&owner).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Achievement Record Result");
+            })
+            .unwrap_or_default()
+    }
+
+    pub async fn get_achievements(&self, owner: Owner) -> Vec<PlayerAchievement> {
+        self.get_achievement_record(owner).await.achievements
+    }
+
+    pub async fn has_achievement(&self, owner: Owner, achievement: Achievement) -> bool {
+        self.get_achievements(owner).await
+            .iter()
+            .any(|player_achievement| player_achievement.achievement == achievement)
+    }
+
+    pub fn get_achievement_bonus(&self, achievement: Achievement) -> Amount {
+        match achievement {
+            Achievement::FirstCow => Amount::from_tokens(FIRST_COW_BONUS),
+            Achievement::OnTimeFeeder => Amount::from_tokens(ON_TIME_FEEDER_BONUS),
+            Achievement::BreedCollector => Amount::from_tokens(BREED_COLLECTOR_BONUS),
+            Achievement::ShrewdSeller => Amount::from_tokens(SHREWD_SELLER_BONUS),
+        }
+    }
+
+    pub async fn record_on_time_feedings(&mut self, owner: Owner, on_time_feedings: u64) -> u64 {
+        let mut record = self.get_achievement_record(owner).await;
+        record.on_time_feedings = record.on_time_feedings.saturating_add(on_time_feedings);
+        let total_on_time_feedings = record.on_time_feedings;
+        self.save_achievement_record(owner, record);
+        total_on_time_feedings
+    }
+
+    pub async fn owns_every_breed(&self, owner: Owner, system_time: Timestamp) -> bool {
+        // a herd smaller than the number of breeds can't have every breed, no need to read the cow db
+        let herd_size = self.get_player_herd_size(owner).await.unwrap_or(0);
+        if herd_size < CowBreed::ALL.len() as u64 {
+            return false;
+        }
+
+        let mut owned_breeds: Vec<CowBreed> = Vec::new();
+        self.cow_db
+            .for_each_index_value(|_, cow| {
+                let is_alive = self.get_cow_status(&cow, system_time) != FeedingStatus::Dead;
+                if cow.owner == owner && is_alive && !owned_breeds.contains(&cow.breed) {
+                    owned_breeds.push(cow.breed);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cow db");
+            });
+        CowBreed::ALL.iter().all(|breed| owned_breeds.contains(breed))
+    }
+
+    pub async fn add_achievement(&mut self, player_achievement: PlayerAchievement) {
+        let owner = player_achievement.owner;
+        let mut record = self.get_achievement_record(owner).await;
+        if record.achievements.iter().any(|awarded| awarded.achievement == player_achievement.achievement) {
+            return;
+        }
+        record.achievements.push(player_achievement);
+        self.save_achievement_record(owner, record);
+    }
+
+    pub fn save_achievement_record(&mut self, owner: Owner, record: AchievementRecord) {
+        self.achievements
+            .insert(&owner, record)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Achievement Record");
+            });
+    }
+}
@@ -0,0 +1,251 @@
+use linera_sdk::base::{Owner, Timestamp};
+use crate::CaretakerGrant;
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Caretaker]
+/// Owner grants another player to feed some or all of the owner's cows, until the grant expires.
+/// Root chain keeps every grant, owner & caretaker chains keep their own copy to check it early.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub async fn get_caretaker_grants(&self, owner: Owner) -> Vec<CaretakerGrant> {
+        self.caretaker_grants.get(&owner).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Caretaker Grant Result");
+            })
+            .unwrap_or_default()
+    }
+
+    pub async fn get_all_caretaker_grants(&self, owner: Option<Owner>, caretaker: Option<Owner>) -> Vec<CaretakerGrant> {
+        let mut grants = Vec::new();
+        self.caretaker_grants
+            .for_each_index_value(|_, grant_list| {
+                for grant in grant_list.into_iter() {
+                    let is_owner = owner.is_none() || owner == Some(grant.owner);
+                    let is_caretaker = caretaker.is_none() || caretaker == Some(grant.caretaker);
+                    if is_owner && is_caretaker {
+                        grants.push(grant);
+                    }
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read caretaker grants");
+            });
+        grants
+    }
+
+    pub async fn check_caretaker_grantable(
+        &self,
+        owner: Owner,
+        caretaker: Owner,
+        cow_names: &[String],
+        expires_at: Timestamp,
+        system_time: Timestamp,
+    ) -> Result<(), String> {
+        if caretaker == owner {
+            return Err(String::from("you can't be your own caretaker"));
+        }
+        if self.get_player(caretaker).await.is_none() {
+            // the grant is sent to the caretaker chain, so the caretaker must be a known player
+            return Err(format!("{:?} has no player chain yet", caretaker));
+        }
+        if expires_at <= system_time {
+            return Err(String::from("caretaker grant must expire in the future"));
+        }
+        if expires_at.micros() - system_time.micros() > CARETAKER_MAX_DURATION {
+            return Err(String::from("caretaker grant can't last more than 30 days"));
+        }
+        for cow_name in cow_names.iter() {
+            let is_owned = self.cow_db.get(cow_name).await
+                .unwrap_or_else(|_| {
+                    panic!("unable to get Cow DB Result");
+                })
+                .is_some_and(|cow| cow.owner == owner);
+            if !is_owned {
+                return Err(format!("{:?} is not yours to grant", cow_name));
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn check_cow_feeder(&self, cow_name: &String, feeder: Owner, system_time: Timestamp) -> Result<Owner, String> {
+        // owner feeds its own cows, anyone else needs an active grant from the owner
+        let cow = self.cow_db.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        if cow.owner == feeder {
+            return Ok(cow.owner);
+        }
+        let is_granted = self.get_caretaker_grants(cow.owner).await
+            .iter()
+            .any(|grant| {
+                grant.caretaker == feeder
+                    && system_time < grant.expires_at
+                    && (grant.cow_names.is_empty() || grant.cow_names.contains(cow_name))
+            });
+        if !is_granted {
+            return Err(format!("you are not allowed to feed {:?}", cow_name));
+        }
+        Ok(cow.owner)
+    }
+
+    pub async fn save_caretaker_grant(&mut self, grant: CaretakerGrant, system_time: Timestamp) {
+        // a new grant replaces the previous grant to the same caretaker, expired grants are no longer kept
+        let owner = grant.owner;
+        let mut grant_list: Vec<CaretakerGrant> = self.get_caretaker_grants(owner).await
+            .into_iter()
+            .filter(|saved_grant| saved_grant.caretaker != grant.caretaker && system_time < saved_grant.expires_at)
+            .collect();
+        grant_list.push(grant);
+        self.caretaker_grants
+            .insert(&owner, grant_list)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Caretaker Grant");
+            });
+    }
+
+    pub async fn remove_caretaker_grant(&mut self, owner: Owner, caretaker: Owner) -> Result<(), String> {
+        let grant_list = self.get_caretaker_grants(owner).await;
+        if grant_list.iter().all(|grant| grant.caretaker != caretaker) {
+            return Err(format!("{:?} is not your caretaker", caretaker));
+        }
+        let grant_list: Vec<CaretakerGrant> = grant_list
+            .into_iter()
+            .filter(|grant| grant.caretaker != caretaker)
+            .collect();
+        self.caretaker_grants
+            .insert(&owner, grant_list)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Caretaker Grant");
+            });
+        Ok(())
+    }
+
+    pub async fn resume_caretaker_grants(&mut self, paused_at: Timestamp, paused_duration: u64) {
+        let mut all_grants = Vec::new();
+        self.caretaker_grants
+            .for_each_index_value(|owner, grant_list| {
+                all_grants.push((owner, grant_list));
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read caretaker grants");
+            });
+
+        // grant expires later by the paused duration, grant that expired before the game paused stays expired
+        for (owner, mut grant_list) in all_grants.into_iter() {
+            for grant in grant_list.iter_mut() {
+                if grant.expires_at > paused_at && grant.granted_at <= paused_at {
+                    grant.expires_at = grant.expires_at.saturating_add_micros(paused_duration);
+                }
+            }
+            self.caretaker_grants
+                .insert(&owner, grant_list)
+                .unwrap_or_else(|_| {
+                    panic!("Failed to update Caretaker Grant");
+                });
+        }
+    }
+
+    pub async fn rename_caretaker_grant_cow(&mut self, cow_name: &String, new_name: &str) {
+        let mut renamed_grants = Vec::new();
+        self.caretaker_grants
+            .for_each_index_value(|owner, grant_list| {
+                if grant_list.iter().any(|grant| grant.cow_names.contains(cow_name)) {
+                    renamed_grants.push((owner, grant_list));
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read caretaker grants");
+            });
+        for (owner, mut grant_list) in renamed_grants.into_iter() {
+            for grant in grant_list.iter_mut() {
+                for granted_cow_name in grant.cow_names.iter_mut() {
+                    if granted_cow_name == cow_name {
+                        *granted_cow_name = new_name.to_string();
+                    }
+                }
+            }
+            self.caretaker_grants
+                .insert(&owner, grant_list)
+                .unwrap_or_else(|_| {
+                    panic!("Failed to update Caretaker Grant");
+                });
+        }
+    }
+
+    pub async fn record_caretaker_feeding(&mut self, owner: Owner, caretaker: Owner, system_time: Timestamp) {
+        let mut grant_list = self.get_caretaker_grants(owner).await;
+        for grant in grant_list.iter_mut() {
+            if grant.caretaker == caretaker {
+                grant.feed_count += 1;
+                grant.last_fed_at = Some(system_time);
+            }
+        }
+        self.caretaker_grants
+            .insert(&owner, grant_list)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Caretaker Grant");
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{ChainId, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::CaretakerGrant;
+    use crate::constants::*;
+    use crate::test_utils::{load_state, test_cow, test_owner};
+
+    #[test]
+    fn caretaker_must_be_a_known_player() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let (owner, caretaker) = (test_owner("01"), test_owner("02"));
+            let system_time = Timestamp::from(1_000);
+            let expires_at = system_time.saturating_add_micros(UNIX_MICROS_IN_24_HOURS);
+
+            assert!(state.check_caretaker_grantable(owner, caretaker, &[], expires_at, system_time).await.is_err());
+            state.record_player_activity(caretaker, ChainId::root(1), system_time).await;
+            assert!(state.check_caretaker_grantable(owner, caretaker, &[], expires_at, system_time).await.is_ok());
+        });
+    }
+
+    #[test]
+    fn renamed_cow_stays_granted() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let (owner, caretaker) = (test_owner("01"), test_owner("02"));
+            let system_time = Timestamp::from(1_000);
+            let cow_name = String::from("Molly");
+            let cow = test_cow(&cow_name, owner, system_time.micros());
+            state.create_new_or_update_cow_data(cow_name.clone(), cow).await;
+            let grant = CaretakerGrant {
+                owner,
+                caretaker,
+                cow_names: vec![cow_name.clone()],
+                granted_at: system_time,
+                expires_at: system_time.saturating_add_micros(UNIX_MICROS_IN_24_HOURS),
+                feed_count: 0,
+                last_fed_at: None,
+            };
+            state.save_caretaker_grant(grant, system_time).await;
+
+            let new_name = String::from("Dolly");
+            state.rename_cow(cow_name, new_name.clone()).await;
+            assert_eq!(state.get_caretaker_grants(owner).await[0].cow_names, vec![new_name.clone()]);
+            assert_eq!(state.check_cow_feeder(&new_name, caretaker, system_time).await, Ok(owner));
+        });
+    }
+}
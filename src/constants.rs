@@ -16,6 +16,19 @@ pub const HEREFORD_PRICE: u128 = 5000;
 pub const HOLSTEIN_PRICE: u128 = 15000;
 pub const SIMMENTAL_PRICE: u128 = 15000;
 
+/// [Feed Price]
+/// ------------------------------------------------------------------------------------------
+pub const BASIC_HAY_PRICE: u128 = 5;
+pub const PREMIUM_GRAIN_PRICE: u128 = 20;
+pub const SUPPLEMENT_PRICE: u128 = 30;
+
+/// [Cow Name]
+/// COW_NAME_MAX_LENGTH = longest cow name, in characters
+/// RENAME_FEE = 100 tokens to rename a cow
+/// ------------------------------------------------------------------------------------------
+pub const COW_NAME_MAX_LENGTH: usize = 32;
+pub const RENAME_FEE: u128 = 100;
+
 /// [Cow Feeding Limit]
 /// WELL_FED = 6 hours
 /// ON_TIME_FED = 12 hours
@@ -37,4 +50,137 @@ pub const LATE_FEED: u64 = 64_800_000_000;
 pub const ON_TIME_REWARD: i128 = 50;
 pub const LATE_REWARD: i128 = 25;
 pub const FORGOT_PENALTY: i128 = 100;
-pub const PRECISION_100_PERCENT: i128 = 10_000;
\ No newline at end of file
+pub const PRECISION_100_PERCENT: i128 = 10_000;
+
+/// [Treasury Reserve]
+/// Minimum ratio of root chain balance against the appraisal price of all living cows,
+/// with 2 digit decimal precision, 100% equivalent to 10_000.
+/// Payouts are scaled down when the reserve falls below this ratio.
+/// ------------------------------------------------------------------------------------------
+pub const MIN_RESERVE_RATIO: u64 = 2_000;
+pub const MAX_MIN_RESERVE_RATIO: u64 = 100_000;
+
+/// [Dynamic Cow Price]
+/// Cow price multiplier, with 2 digit decimal precision, 100% equivalent to 10_000.
+/// For every breed, its price will change by:
+/// +1% for every recent buy -- 100
+/// -1% for every recent sell -- 100
+/// -0.1% for every living cow -- 10
+/// Recent buys & sells are counted within 24 hours window.
+/// Default price bounds are 50% -- 5_000 and 200% -- 20_000 of the breed base price.
+/// ------------------------------------------------------------------------------------------
+pub const PRICE_DEMAND_STEP: i128 = 100;
+pub const PRICE_SUPPLY_STEP: i128 = 10;
+pub const PRICE_MARKET_WINDOW: u64 = 86_400_000_000;
+pub const PRICE_MIN_BOUND: u128 = 5_000;
+pub const PRICE_MAX_BOUND: u128 = 20_000;
+
+/// [Seasonal Event]
+/// Stacked breed discounts of active events can't bring the price below 10% -- 1_000 of the breed price.
+/// ------------------------------------------------------------------------------------------
+pub const EVENT_PRICE_FLOOR: u128 = 1_000;
+
+/// [Daily Reward]
+/// DAILY_REWARD = 100 tokens for the 1st day of a streak
+/// DAILY_REWARD_STREAK_BONUS = 20 tokens more for every consecutive day, until DAILY_REWARD_MAX_STREAK
+/// Reward can be claimed again 24 hours after the previous claim,
+/// and the streak is reset when it's not claimed within 48 hours after the previous claim.
+/// all time unit is in Unix Micros
+/// ------------------------------------------------------------------------------------------
+pub const DAILY_REWARD: u128 = 100;
+pub const DAILY_REWARD_STREAK_BONUS: u128 = 20;
+pub const DAILY_REWARD_MAX_STREAK: u64 = 7;
+pub const DAILY_REWARD_COOLDOWN: u64 = 86_400_000_000;
+pub const DAILY_REWARD_STREAK_WINDOW: u64 = 172_800_000_000;
+
+/// [Loan]
+/// Loan and interest rate are using 2 digit decimal precision, 100% equivalent to 10_000.
+/// LOAN_TO_VALUE = 50% of the pledged cows sell value at maximum -- 5_000
+/// LOAN_INTEREST_RATE = 1% of the principal for every started 24 hours -- 100
+/// LOAN_DURATION = 7 days, in Unix Micros
+/// ------------------------------------------------------------------------------------------
+pub const LOAN_TO_VALUE: u128 = 5_000;
+pub const LOAN_INTEREST_RATE: u64 = 100;
+pub const LOAN_DURATION: u64 = 604_800_000_000;
+
+/// [Insurance]
+/// Insurance rates are using 2 digit decimal precision, 100% equivalent to 10_000.
+/// INSURANCE_COVERAGE = 50% of the cow sell value when the policy is bought -- 5_000
+/// INSURANCE_PREMIUM_RATE = 5% of the coverage -- 500
+/// For every feeding in the cow history, the premium rate changes by:
+/// -0.2% when ON_TIME -- 20
+/// +0.5% when LATE -- 50
+/// +2% when FORGOT -- 200
+/// Premium rate stays between 1% -- 100 and 50% -- 5_000
+/// INSURANCE_DURATION = 7 days, in Unix Micros
+/// ------------------------------------------------------------------------------------------
+pub const INSURANCE_COVERAGE: u128 = 5_000;
+pub const INSURANCE_PREMIUM_RATE: i128 = 500;
+pub const INSURANCE_ON_TIME_DISCOUNT: i128 = 20;
+pub const INSURANCE_LATE_LOADING: i128 = 50;
+pub const INSURANCE_FORGOT_LOADING: i128 = 200;
+pub const INSURANCE_MIN_PREMIUM_RATE: i128 = 100;
+pub const INSURANCE_MAX_PREMIUM_RATE: i128 = 5_000;
+pub const INSURANCE_DURATION: u64 = 604_800_000_000;
+
+/// [Farm]
+/// Every farm starts at level 1, and each level adds more room in the barn.
+/// FARM_BASE_CAPACITY = 5 cows at level 1
+/// FARM_CAPACITY_PER_LEVEL = 5 cows more for every level after the 1st
+/// FARM_UPGRADE_COST = 2000 tokens multiplied by the current level
+/// ------------------------------------------------------------------------------------------
+pub const FARM_BASE_CAPACITY: u64 = 5;
+pub const FARM_CAPACITY_PER_LEVEL: u64 = 5;
+pub const FARM_MAX_LEVEL: u64 = 10;
+pub const FARM_UPGRADE_COST: u128 = 2000;
+
+/// [Cow Health]
+/// Sickness chance and penalty are using 2 digit decimal precision, 100% equivalent to 10_000.
+/// Every LATE or FORGOT feeding of a healthy cow rolls for sickness, the chance grows with the cow history:
+/// +2% for every LATE feeding -- 200
+/// +5% for every FORGOT feeding -- 500
+/// Sickness chance stays below 50% -- 5_000
+/// SICKNESS_PENALTY = 10% of the cow base price for every started 24 hours of sickness -- 1_000
+/// SICKNESS_DEATH_TIME = a sick cow dies 48 hours after falling ill, in Unix Micros
+/// TREATMENT_PRICE = 200 tokens to cure a sick cow
+/// ------------------------------------------------------------------------------------------
+pub const SICKNESS_LATE_CHANCE: u64 = 200;
+pub const SICKNESS_FORGOT_CHANCE: u64 = 500;
+pub const SICKNESS_MAX_CHANCE: u64 = 5_000;
+pub const SICKNESS_PENALTY: u128 = 1_000;
+pub const SICKNESS_DEATH_TIME: u64 = 172_800_000_000;
+pub const TREATMENT_PRICE: u128 = 200;
+
+/// [Achievement]
+/// ON_TIME_FEEDER_COUNT = ON TIME feedings of all the player's cows to get OnTimeFeeder
+/// SHREWD_SELLER_MULTIPLIER = selling price must be more than 2x the breed base price to get ShrewdSeller
+/// One-time bonus in tokens, scaled by the treasury reserve health:
+/// FirstCow -- 0, OnTimeFeeder -- 500, BreedCollector -- 1000, ShrewdSeller -- 300
+/// ------------------------------------------------------------------------------------------
+pub const ON_TIME_FEEDER_COUNT: u64 = 100;
+pub const SHREWD_SELLER_MULTIPLIER: u128 = 2;
+pub const FIRST_COW_BONUS: u128 = 0;
+pub const ON_TIME_FEEDER_BONUS: u128 = 500;
+pub const BREED_COLLECTOR_BONUS: u128 = 1000;
+pub const SHREWD_SELLER_BONUS: u128 = 300;
+
+/// [Cooperative]
+/// COOP_MAX_MEMBERS = members of a cooperative, including the founder
+/// COOP_MAX_COWS = living cows in a cooperative herd
+/// COOP_NAME_MAX_LENGTH = characters of a cooperative name
+/// COOP_OWNER_TAG = marks the herd owner derived from the cooperative id
+/// ------------------------------------------------------------------------------------------
+pub const COOP_MAX_MEMBERS: usize = 10;
+pub const COOP_MAX_COWS: u64 = 20;
+pub const COOP_NAME_MAX_LENGTH: usize = 32;
+pub const COOP_OWNER_TAG: u64 = 0x4d43_4f4f_5045_5241;
+
+/// [Caretaker]
+/// CARETAKER_MAX_DURATION = a caretaker grant lasts 30 days at most, in Unix Micros
+/// ------------------------------------------------------------------------------------------
+pub const CARETAKER_MAX_DURATION: u64 = 2_592_000_000_000;
+
+/// [Keeper]
+/// KEEPER_FEE = 2 tokens paid to the keeper for every ON TIME feeding, on top of the feed price
+/// ------------------------------------------------------------------------------------------
+pub const KEEPER_FEE: u128 = 2;
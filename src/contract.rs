@@ -1,20 +1,19 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-mod state;
 mod random;
-mod constants;
 
 use linera_sdk::{
     base::WithContractAbi,
     views::{RootView, View, ViewStorageContext},
     Contract, ContractRuntime,
 };
-use linera_sdk::base::{Amount, ChannelName, Destination, Owner, Timestamp};
-use micro_cow_linera::{BuyNotif, CowBreed, CowBuyParams, CowData, CowGender, CowOperation, FeedingStats, Message, MicroCowParameters, SellNotif};
-use crate::constants::*;
-use crate::random::{custom_getrandom, truncate};
+use linera_sdk::base::{Amount, ChainId, ChannelName, Destination, Owner, Timestamp};
+use micro_cow_linera::{Achievement, AchievementNotif, BuyNotif, CaretakerAction, CaretakerGrant, CaretakerNotif, CoopAction, CoopNotif, CoopRule, Cooperative, CowBreed, CowBuyParams, CowData, CowGender, CowHealth, CowOperation, CowSale, DailyReward, FarmNotif, FeedInventory, FeedNotif, FeedType, FeedingStats, FeedingStatus, HealthNotif, InsuranceNotif, InsurancePolicy, KeeperAction, KeeperBudget, KeeperNotif, Loan, LoanNotif, Message, MicroCowParameters, PauseState, PlayerAchievement, QuestNotif, QuestProgress, RenameNotif, RewardNotif, SellNotif, ShopNotif};
+use micro_cow_linera::constants::*;
+use micro_cow_linera::quests::QuestAction;
+use crate::random::{custom_getrandom, mix_seed, seeded_getrandom, truncate};
 
-use self::state::MicroCow;
+use micro_cow_linera::state::MicroCow;
 
 pub struct MicroCowContract {
     state: MicroCow,
@@ -33,9 +32,10 @@ impl Contract for MicroCowContract {
     type InstantiationArgument = Amount;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
-        let state = MicroCow::load(ViewStorageContext::from(runtime.key_value_store()))
+        let mut state = MicroCow::load(ViewStorageContext::from(runtime.key_value_store()))
             .await
             .expect("Failed to load state");
+        state.migrate().await;
         MicroCowContract { state, runtime }
     }
 
@@ -61,12 +61,18 @@ impl Contract for MicroCowContract {
             );
 
             self.state.initialize(owner, chain_id, amount, true).await;
+            self.state.add_admin(owner);
         }
     }
 
     async fn execute_operation(&mut self, _operation: Self::Operation) -> Self::Response {
-        // root chain are not allowed to play
-        self.check_root_invocation();
+        // admin operation only allowed on root chain,
+        // and root chain are not allowed to play
+        if _operation.is_admin_operation() {
+            self.check_admin_invocation();
+        } else {
+            self.check_root_invocation();
+        }
 
         match _operation {
             CowOperation::Initialize => {
@@ -79,11 +85,63 @@ impl Contract for MicroCowContract {
             }
             CowOperation::DeleteBuyNotification => {
                 log::info!("CowOperation::DeleteBuyNotification");
-                self.state.delete_buy_notification().await
+                MicroCow::delete_notification(&mut self.state.buy_notification)
             }
             CowOperation::DeleteSellNotification => {
                 log::info!("CowOperation::DeleteSellNotification");
-                self.state.delete_sell_notification().await
+                MicroCow::delete_notification(&mut self.state.sell_notification)
+            }
+            CowOperation::DeleteFeedNotification => {
+                log::info!("CowOperation::DeleteFeedNotification");
+                MicroCow::delete_notification(&mut self.state.feed_notification)
+            }
+            CowOperation::DeleteRewardNotification => {
+                log::info!("CowOperation::DeleteRewardNotification");
+                MicroCow::delete_notification(&mut self.state.reward_notification)
+            }
+            CowOperation::DeleteLoanNotification => {
+                log::info!("CowOperation::DeleteLoanNotification");
+                MicroCow::delete_notification(&mut self.state.loan_notification)
+            }
+            CowOperation::DeleteInsuranceNotification => {
+                log::info!("CowOperation::DeleteInsuranceNotification");
+                MicroCow::delete_notification(&mut self.state.insurance_notification)
+            }
+            CowOperation::DeleteShopNotification => {
+                log::info!("CowOperation::DeleteShopNotification");
+                MicroCow::delete_notification(&mut self.state.shop_notification)
+            }
+            CowOperation::DeleteFarmNotification => {
+                log::info!("CowOperation::DeleteFarmNotification");
+                MicroCow::delete_notification(&mut self.state.farm_notification)
+            }
+            CowOperation::DeleteHealthNotification => {
+                log::info!("CowOperation::DeleteHealthNotification");
+                MicroCow::delete_notification(&mut self.state.health_notification)
+            }
+            CowOperation::DeleteAchievementNotification => {
+                log::info!("CowOperation::DeleteAchievementNotification");
+                MicroCow::delete_notification(&mut self.state.achievement_notification)
+            }
+            CowOperation::DeleteQuestNotification => {
+                log::info!("CowOperation::DeleteQuestNotification");
+                MicroCow::delete_notification(&mut self.state.quest_notification)
+            }
+            CowOperation::DeleteRenameNotification => {
+                log::info!("CowOperation::DeleteRenameNotification");
+                MicroCow::delete_notification(&mut self.state.rename_notification)
+            }
+            CowOperation::DeleteCoopNotification => {
+                log::info!("CowOperation::DeleteCoopNotification");
+                MicroCow::delete_notification(&mut self.state.coop_notification)
+            }
+            CowOperation::DeleteCaretakerNotification => {
+                log::info!("CowOperation::DeleteCaretakerNotification");
+                MicroCow::delete_notification(&mut self.state.caretaker_notification)
+            }
+            CowOperation::DeleteKeeperNotification => {
+                log::info!("CowOperation::DeleteKeeperNotification");
+                MicroCow::delete_notification(&mut self.state.keeper_notification)
             }
             CowOperation::Subscribe => {
                 log::info!("CowOperation::Subscribe");
@@ -94,6 +152,11 @@ impl Contract for MicroCowContract {
                 // check authentication
                 self.check_authentication(owner);
 
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
                 // check cow name in DB and available to buy
                 // you can't buy if cow exist and alive
                 let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(
@@ -110,12 +173,17 @@ impl Contract for MicroCowContract {
                     panic!("you can't revive {:?}", cow_name);
                 }
 
+                // make sure there is room in the barn, root chain will check it again
+                self.state.check_barn_capacity(owner, self.runtime.system_time()).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
                 // cow gender
                 let cow_gender = self.random_cow_gender().await;
 
                 // check if owner have enough balance to buy the cow
                 let owner_balance = self.state.app_data.get().balance;
-                let cow_price = self.state.get_cow_price(cow_breed);
+                let cow_price = self.state.get_event_cow_price(cow_breed, self.runtime.system_time());
                 let balance_is_enough = owner_balance.gt(&cow_price);
                 if !balance_is_enough {
                     panic!("{:?} don't have enough balance to buy {:?}", owner, cow_name);
@@ -123,6 +191,7 @@ impl Contract for MicroCowContract {
 
                 // debit owner balance to pay for the cow
                 self.state.debit(cow_price).await;
+                self.state.record_quest_action(owner, QuestAction::BuyCow(cow_breed), self.runtime.system_time()).await;
 
                 // send BuyCow message to root chain
                 let message = Message::BuyCow {
@@ -135,11 +204,48 @@ impl Contract for MicroCowContract {
                     .with_tracking()
                     .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            CowOperation::FeedCow { owner, cow_name } => {
+            CowOperation::BuyFeed { owner, feed_type, quantity } => {
+                log::info!("CowOperation::BuyFeed");
+                // check authentication
+                self.check_authentication(owner);
+
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                if quantity == 0 {
+                    panic!("quantity must be more than 0");
+                }
+
+                // check if owner have enough balance to buy the feed
+                let owner_balance = self.state.app_data.get().balance;
+                let payment = self.state.get_feed_cost(feed_type, quantity);
+                if owner_balance.lt(&payment) {
+                    panic!("{:?} don't have enough balance to buy {} {:?}", owner, quantity, feed_type);
+                }
+
+                // debit owner balance to pay for the feed
+                self.state.debit(payment).await;
+
+                // send BuyFeed message to root chain
+                let message = Message::BuyFeed { owner, feed_type, quantity, payment };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::FeedCow { owner, cow_name, feed_type } => {
                 log::info!("CowOperation::FeedCow");
                 // check authentication
                 self.check_authentication(owner);
 
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
                 // Rule for FeedCow
                 // we have 4 feeding time zone, that is every 6 hours.
                 //
@@ -151,482 +257,4751 @@ impl Contract for MicroCowContract {
                 //
                 // if feeding distance are less than 6 hours, the cow won't eat, still full.
                 // if feeding distance are more than 24 hours, the cow will die.
+                //
+                // every feeding uses up 1 feed item of the chosen type.
 
-                // make sure the cow is existing both on local DB and in ownership
-                // if not exist, probably the cow isn't yours to feed
-                let is_cow_exist = self.state
-                    .is_cow_exist_in_db_and_ownership(cow_name.clone()).await;
-                if !is_cow_exist {
-                    panic!("{:?} is not exist", cow_name);
-                }
+                // feed the cow, and update its feeding stats
+                let cow = self.feed_cow(cow_name, feed_type).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+                self.state.use_feed(owner, feed_type, 1).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                // check cow name in DB and available to feed
-                // you can't feed if cow already died
-                let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(
-                    cow_name.clone(), self.runtime.system_time(),
-                ).await;
-                if !is_cow_alive_and_exist {
-                    panic!("you can't feed {:?}", cow_name);
-                }
+                // send FeedCow message to root chain
+                let message = Message::FeedCow { owner, cow_data: cow, feed_type };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::FeedCows { owner, cow_names, feed_type } => {
+                log::info!("CowOperation::FeedCows");
+                // check authentication
+                self.check_authentication(owner);
 
-                // get CowData from DB
-                let mut cow = self.state.cow_db.get(&cow_name).await
-                    .unwrap_or_else(|_| {
-                        panic!("unable to get Cow DB Result");
-                    }).unwrap_or_else(|| {
-                    panic!("unable to get Cow DB Option");
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                self.feed_cows(owner, cow_names, feed_type).await;
+            }
+            CowOperation::FeedAllCows { owner, feed_type } => {
+                log::info!("CowOperation::FeedAllCows");
+                // check authentication
+                self.check_authentication(owner);
+
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // feed all of my cow
+                let cow_names = self.state.get_my_cows().await
+                    .into_iter()
+                    .map(|cow| cow.name)
+                    .collect();
+                self.feed_cows(owner, cow_names, feed_type).await;
+            }
+            CowOperation::SellCow { owner, cow_name, cow_born_time } => {
+                log::info!("CowOperation::SellCow");
+                // check authentication
+                self.check_authentication(owner);
+
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
                 });
 
-                // find out feeding distance
-                let current_time = self.runtime.system_time().micros();
-                let last_fed_time = cow.last_fed_time.micros();
-                let feed_distance = current_time - last_fed_time;
+                // make sure the cow can be sold
+                self.check_cow_sellable(cow_name.clone(), cow_born_time).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                if feed_distance <= WELL_FED {
-                    panic!("{:?} still full", cow_name);
-                }
+                self.state.record_quest_action(owner, QuestAction::SellCow, self.runtime.system_time()).await;
+                let message = Message::SellCow { owner, cow_name };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::SellCows { owner, cow_names } => {
+                log::info!("CowOperation::SellCows");
+                // check authentication
+                self.check_authentication(owner);
 
-                // calculate feeding stats
-                let mut on_time = cow.feeding_stats.on_time;
-                let mut late = cow.feeding_stats.late;
-                let mut forgot = cow.feeding_stats.forgot;
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                if feed_distance > WELL_FED && feed_distance <= ON_TIME_FEED {
-                    on_time = on_time + 1;
+                // cow that can't be sold will be notified, and excluded from the batch
+                let mut sellable_cow_names = Vec::new();
+                for cow_name in cow_names.into_iter() {
+                    let cow_born_time = self.state.cow_db.get(&cow_name).await
+                        .unwrap_or_else(|_| {
+                            panic!("unable to get Cow DB Result");
+                        })
+                        .map(|cow| cow.born_time)
+                        .unwrap_or_default();
+                    match self.check_cow_sellable(cow_name.clone(), cow_born_time).await {
+                        Ok(()) => sellable_cow_names.push(cow_name),
+                        Err(reason) => self.state.sell_notification.push_back(SellNotif {
+                            cow_name,
+                            is_success: false,
+                            failure_reason: reason,
+                        }),
+                    }
                 }
-                if feed_distance > ON_TIME_FEED && feed_distance <= LATE_FEED {
-                    late = late + 1;
+                if sellable_cow_names.is_empty() {
+                    return;
                 }
-                if feed_distance > LATE_FEED {
-                    forgot = forgot + 1;
+                for _ in sellable_cow_names.iter() {
+                    self.state.record_quest_action(owner, QuestAction::SellCow, self.runtime.system_time()).await;
                 }
 
-                // update cow data
-                cow.last_fed_time = Timestamp::from(current_time);
-                cow.feeding_stats = FeedingStats { on_time, late, forgot };
+                let message = Message::SellCows { owner, cow_names: sellable_cow_names };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::ClaimDailyReward { owner } => {
+                log::info!("CowOperation::ClaimDailyReward");
+                // check authentication
+                self.check_authentication(owner);
+
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                // save data to db
-                self.state.create_new_or_update_cow_data(cow_name, cow.clone()).await;
+                // check the cooldown from our last claim, root chain will check it again
+                let daily_reward = self.state.get_daily_reward(owner).await;
+                self.state
+                    .check_daily_reward_claimable(daily_reward.as_ref(), self.runtime.system_time())
+                    .unwrap_or_else(|reason| {
+                        panic!("{}", reason);
+                    });
 
-                // send FeedCow message to root chain
-                let message = Message::FeedCow { owner, cow_data: cow };
+                // send ClaimDailyReward message to root chain
+                let message = Message::ClaimDailyReward { owner };
                 self.runtime
                     .prepare_message(message)
                     .with_authentication()
+                    .with_tracking()
                     .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            CowOperation::SellCow { owner, cow_name, cow_born_time } => {
-                log::info!("CowOperation::SellCow");
+            CowOperation::ClaimQuestReward { owner, quest_index } => {
+                log::info!("CowOperation::ClaimQuestReward");
                 // check authentication
                 self.check_authentication(owner);
 
-                // make sure the cow is existing both on local DB and in ownership
-                // if not exist, probably the cow isn't yours to sell
-                let is_cow_exist = self.state
-                    .is_cow_exist_in_db_and_ownership(cow_name.clone()).await;
-                if !is_cow_exist {
-                    panic!("{:?} is not exist", cow_name);
-                }
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                // check cow name in DB and available to sell
-                // you can't sell if cow already died
-                let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(
-                    cow_name.clone(), self.runtime.system_time(),
-                ).await;
-                if !is_cow_alive_and_exist {
-                    panic!("you can't sell {:?}", cow_name);
-                }
+                // check our quest progress, root chain will check it again
+                self.state
+                    .check_quest_claimable(owner, quest_index, self.runtime.system_time())
+                    .await
+                    .unwrap_or_else(|reason| {
+                        panic!("{}", reason);
+                    });
 
-                // check if cow is underage
-                let system_time = self.runtime.system_time();
-                let is_cow_underage = self.state.is_cow_underage(cow_born_time, system_time).await;
-                if is_cow_underage {
-                    panic!("{:?} is too young to be sold", cow_name);
-                }
+                // send ClaimQuestReward message to root chain
+                let message = Message::ClaimQuestReward { owner, quest_index };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::RenameCow { owner, cow_name, new_name } => {
+                log::info!("CowOperation::RenameCow");
+                // check authentication
+                self.check_authentication(owner);
 
-                let message = Message::SellCow { owner, cow_name };
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // check the cow can be renamed, root chain will check it again
+                self.check_cow_renamable(cow_name.clone(), &new_name).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // debit owner balance to pay the renaming fee
+                let payment = Amount::from_tokens(RENAME_FEE);
+                self.state.debit(payment).await;
+
+                // send RenameCow message to root chain
+                let message = Message::RenameCow { owner, cow_name, new_name, payment };
                 self.runtime
                     .prepare_message(message)
                     .with_authentication()
                     .with_tracking()
                     .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            CowOperation::BuryDeadCows => {
-                log::info!("CowOperation::BuryDeadCows");
-                // get all of my cow
-                let my_cows = self.state.get_my_cows().await;
+            CowOperation::CreateCoop { owner, name, members, feed_rule, trade_rule } => {
+                log::info!("CowOperation::CreateCoop");
+                // check authentication
+                self.check_authentication(owner);
 
-                // filter cow who has died
-                let current_time = self.runtime.system_time().micros();
-                for cow in my_cows.into_iter() {
-                    let last_fed_time = cow.last_fed_time.micros();
-                    let last_fed_after_24_hours = last_fed_time + UNIX_MICROS_IN_24_HOURS;
-                    if current_time.gt(&last_fed_after_24_hours) {
-                        // cow last fed time is more than 24 hours, therefore it has died
-                        // remove cow from DB
-                        self.state.cow_db.remove(&cow.name).unwrap_or_else(|_| {
-                            panic!("unable to remove Cow from DB");
-                        });
-                        self.state.cow_ownership.remove(&cow.name).unwrap_or_else(|_| {
-                            panic!("unable to remove Cow from ownership");
-                        });
-                    }
-                }
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // send CreateCoop message to root chain
+                let message = Message::CreateCoop { owner, name, members, feed_rule, trade_rule };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
             }
-        }
-    }
+            CowOperation::DepositToCoop { owner, coop_id, amount } => {
+                log::info!("CowOperation::DepositToCoop");
+                // check authentication
+                self.check_authentication(owner);
 
-    async fn execute_message(&mut self, _message: Self::Message) {
-        let is_bouncing = self
-            .runtime
-            .message_is_bouncing()
-            .unwrap_or_else(|| {
-                panic!("Message delivery status has to be available when executing a message");
-            });
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-        let message_id = self
-            .runtime
-            .message_id()
-            .unwrap_or_else(|| {
-                panic!("Message ID has to be available when executing a message");
-            });
+                if amount.eq(&Amount::ZERO) {
+                    panic!("deposit must be more than 0");
+                }
 
-        match _message {
-            // ! executed by ROOT chain
-            // ! --------------------------------------------------------------------------------
-            Message::BuyCow { owner, cow_buy_params } => {
-                if is_bouncing {
-                    // ? BOUNCING parts executed by USER chain
-                    // credit balance due to failure to BuyCow
-                    log::info!("Message::BuyCow - Fail to Buy Cow: {:?}", cow_buy_params.name.clone());
-                    self.state.credit(cow_buy_params.price).await;
-                    self.state.buy_notification.push_back(
-                        BuyNotif {
-                            cow_name: cow_buy_params.name,
-                            is_success: false,
-                        });
-                    return;
+                // check if owner have enough balance to deposit
+                let owner_balance = self.state.app_data.get().balance;
+                if owner_balance.lt(&amount) {
+                    panic!("{:?} don't have enough balance to deposit {}", owner, amount);
                 }
 
-                log::info!("Message::BuyCow");
+                // debit owner balance to deposit into the cooperative treasury
+                self.state.debit(amount).await;
 
+                // send DepositToCoop message to root chain
+                let message = Message::DepositToCoop { owner, coop_id, amount };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::CoopBuyCow { owner, coop_id, cow_name, cow_id, cow_breed } => {
+                log::info!("CowOperation::CoopBuyCow");
                 // check authentication
                 self.check_authentication(owner);
 
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
                 // check cow name in DB and available to buy
                 // you can't buy if cow exist and alive
                 let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(
-                    cow_buy_params.name.clone(), self.runtime.system_time(),
+                    cow_name.clone(), self.runtime.system_time(),
                 ).await;
                 if is_cow_alive_and_exist {
-                    let cow_data = self.state.cow_db.get(&cow_buy_params.name.clone()).await
-                        .unwrap_or_else(|_| {
-                            panic!("unable to get Cow DB Result");
-                        }).unwrap_or_else(|| {
-                        panic!("unable to get Cow DB Option");
-                    });
-
-                    let message = Message::BuyFailure { cow_data, cow_buy_params };
-                    self.runtime
-                        .prepare_message(message)
-                        .send_to(message_id.chain_id);
-                    return;
+                    panic!("{:?} is not available", cow_name);
                 }
 
-                // new cow data.
-                let new_cow_data = CowData {
-                    id: cow_buy_params.id,
-                    name: cow_buy_params.name.clone(),
-                    breed: cow_buy_params.breed,
-                    gender: cow_buy_params.gender,
-                    born_time: self.runtime.system_time(),
-                    last_fed_time: self.runtime.system_time(),
-                    feeding_stats: FeedingStats::new(),
+                // the cow is paid by the cooperative treasury, at the price root chain sees
+                let cow_gender = self.random_cow_gender().await;
+                let cow_price = self.state.get_event_cow_price(cow_breed, self.runtime.system_time());
+
+                // send CoopBuyCow message to root chain
+                let message = Message::CoopBuyCow {
                     owner,
+                    coop_id,
+                    cow_buy_params: CowBuyParams::new(cow_id, cow_name, cow_breed, cow_gender, cow_price),
                 };
-                self.state.create_new_or_update_cow_data(cow_buy_params.name, new_cow_data.clone()).await;
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::CoopFeedCow { owner, coop_id, cow_name, feed_type } => {
+                log::info!("CowOperation::CoopFeedCow");
+                // check authentication
+                self.check_authentication(owner);
 
-                // credit balance to receive payment for the cow
-                self.state.credit(cow_buy_params.price).await;
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                // notify to channel subscriber that a BuyCow is success
-                let message = Message::BuySuccess { cow_data: new_cow_data };
+                // the feed is paid by the cooperative treasury, root chain feeds the cow on its own clock
+                let message = Message::CoopFeedCow { owner, coop_id, cow_name, feed_type };
                 self.runtime
                     .prepare_message(message)
-                    .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            Message::FeedCow { owner, cow_data } => {
-                log::info!("Message::FeedCow");
-                // Message::FeedCow not being tracked
-                // Even if it does, bouncing message should do nothing.
-                if is_bouncing {
-                    return;
-                }
-
+            CowOperation::CoopSellCow { owner, coop_id, cow_name } => {
+                log::info!("CowOperation::CoopSellCow");
                 // check authentication
                 self.check_authentication(owner);
 
-                // save data to db
-                let cow_name = cow_data.name.clone();
-                self.state.create_new_or_update_cow_data(cow_name, cow_data.clone()).await;
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                // notify to channel subscriber that a FeedCow is success
-                let message = Message::FeedSuccess { cow_data };
+                // send CoopSellCow message to root chain
+                let message = Message::CoopSellCow { owner, coop_id, cow_name };
                 self.runtime
                     .prepare_message(message)
-                    .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
-            }
-            Message::SellCow { owner, cow_name } => {
-                if is_bouncing {
-                    // ? BOUNCING parts executed by USER chain
-                    log::info!("Message::SellCow - Fail to Sell Cow: {:?}", cow_name);
-                    self.state.sell_notification.push_back(SellNotif {
-                        cow_name,
-                        is_success: false,
-                        failure_reason: String::from("Failure to sell, operation bounced"),
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::DissolveCoop { owner, coop_id } => {
+                log::info!("CowOperation::DissolveCoop");
+                // check authentication
+                self.check_authentication(owner);
+
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // send DissolveCoop message to root chain
+                let message = Message::DissolveCoop { owner, coop_id };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::GrantCaretaker { owner, caretaker, cow_names, expires_at } => {
+                log::info!("CowOperation::GrantCaretaker");
+                // check authentication
+                self.check_authentication(owner);
+
+                // check the grant from our local state, root chain will check it again
+                let system_time = self.runtime.system_time();
+                self.state
+                    .check_caretaker_grantable(owner, caretaker, &cow_names, expires_at, system_time).await
+                    .unwrap_or_else(|reason| {
+                        panic!("{}", reason);
                     });
-                    return;
-                }
 
-                log::info!("Message::SellCow");
+                // send GrantCaretaker message to root chain
+                let message = Message::GrantCaretaker { owner, caretaker, cow_names, expires_at };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::RevokeCaretaker { owner, caretaker } => {
+                log::info!("CowOperation::RevokeCaretaker");
+                // check authentication
+                self.check_authentication(owner);
 
+                // send RevokeCaretaker message to root chain
+                let message = Message::RevokeCaretaker { owner, caretaker };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::CaretakerFeedCow { owner, cow_name, feed_type } => {
+                log::info!("CowOperation::CaretakerFeedCow");
                 // check authentication
                 self.check_authentication(owner);
 
-                // get CowData from DB
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // make sure the cow owner let us feed the cow, root chain will check it again
+                let system_time = self.runtime.system_time();
+                self.state.check_cow_feeder(&cow_name, owner, system_time).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // feed the cow with our own feed item, following the same feeding rule
                 let cow = self.state.cow_db.get(&cow_name).await
                     .unwrap_or_else(|_| {
                         panic!("unable to get Cow DB Result");
                     }).unwrap_or_else(|| {
                     panic!("unable to get Cow DB Option");
                 });
+                let previous_on_time = cow.feeding_stats.on_time;
+                let cow = self.get_fed_cow(cow, feed_type).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+                self.state.use_feed(owner, feed_type, 1).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                // calculate cow selling price & check contract balance
-                let cow_selling_price = self.state.cow_sell_value(cow.clone()).await;
-                let contract_balance = self.state.app_data.get().balance;
-                if contract_balance.lt(&cow_selling_price) {
-                    let reason = String::from("Insufficient contract balance");
-                    let message = Message::SellFailure { cow_name, reason };
-                    self.runtime
-                        .prepare_message(message)
-                        .send_to(message_id.chain_id);
-                    return;
+                // count quest progress for the caretaker
+                self.state.record_quest_action(owner, QuestAction::FeedCow, system_time).await;
+                if cow.feeding_stats.on_time > previous_on_time {
+                    self.state.record_quest_action(owner, QuestAction::FeedOnTime, system_time).await;
                 }
+                self.state.create_new_or_update_cow_data(cow_name, cow.clone()).await;
+
+                // send FeedCow message to root chain
+                let message = Message::FeedCow { owner, cow_data: cow, feed_type };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::FundKeeperBudget { owner, cow_name, feed_type, amount } => {
+                log::info!("CowOperation::FundKeeperBudget");
+                // check authentication
+                self.check_authentication(owner);
 
-                // remove Cow from DB
-                self.state.cow_db.remove(&cow_name).unwrap_or_else(|_| {
-                    panic!("unable to remove Cow from DB");
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
                 });
 
-                // debit contract balance to pay for the cow
-                self.state.debit(cow_selling_price.clone()).await;
+                // make sure the cow is existing both on local DB and in ownership
+                let is_cow_exist = self.state
+                    .is_cow_exist_in_db_and_ownership(cow_name.clone()).await;
+                if !is_cow_exist {
+                    panic!("{:?} is not exist", cow_name);
+                }
+                let system_time = self.runtime.system_time();
+                self.state.check_keeper_fundable(owner, &cow_name, system_time).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                // notify to channel subscriber that a SellCow is success
-                let message = Message::SellSuccess {
-                    cow_name,
-                    cow_owner: cow.owner,
-                    payment: cow_selling_price,
-                };
+                if amount.eq(&Amount::ZERO) {
+                    panic!("keeper budget must be more than 0");
+                }
+
+                // check if owner have enough balance to fund the budget
+                let owner_balance = self.state.app_data.get().balance;
+                if owner_balance.lt(&amount) {
+                    panic!("{:?} don't have enough balance to fund {}", owner, amount);
+                }
+
+                // debit owner balance to prepay the keeper budget
+                self.state.debit(amount).await;
+
+                // send FundKeeperBudget message to root chain
+                let message = Message::FundKeeperBudget { owner, cow_name, feed_type, amount };
                 self.runtime
                     .prepare_message(message)
-                    .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            Message::Subscribe => {
-                log::info!("Message::Subscribe");
-                if is_bouncing {
-                    // ? BOUNCING parts executed by USER chain
-                    // nothing happens for now
-                    return;
-                }
+            CowOperation::WithdrawKeeperBudget { owner, cow_name } => {
+                log::info!("CowOperation::WithdrawKeeperBudget");
+                // check authentication
+                self.check_authentication(owner);
 
-                // register the Chain ID from message as subscriber for MICRO_COW_CHANNEL
-                self.runtime.subscribe(
-                    message_id.chain_id,
-                    ChannelName::from(MICRO_COW_CHANNEL.to_vec()),
-                );
+                // send WithdrawKeeperBudget message to root chain
+                let message = Message::WithdrawKeeperBudget { owner, cow_name };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            // ! executed by USER chain
-            // ! --------------------------------------------------------------------------------
-            Message::BuySuccess { cow_data } => {
-                log::info!("Message::BuySuccess");
-                // Message::BuySuccess not being tracked
-                // Even if it does, bouncing message should do nothing.
-                if is_bouncing {
-                    return;
-                }
-
-                // save new CowData to local state in all Micro Cow channel subscriber
-                let cow_name = cow_data.name.clone();
-                self.state.create_new_or_update_cow_data(cow_name.clone(), cow_data.clone()).await;
+            CowOperation::KeeperFeed { owner, cow_name } => {
+                log::info!("CowOperation::KeeperFeed");
+                // check authentication
+                self.check_authentication(owner);
 
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-                // update Cow Ownership & Buy Notification only on Buyer's local state
-                let owner = self.state.app_data.get().owner;
-                if owner.eq(&cow_data.owner) {
-                    self.state.update_cow_ownership(cow_name.clone()).await;
-                    self.state.buy_notification.push_back(BuyNotif {
-                        cow_name: cow_name.clone(),
-                        is_success: true,
-                    });
-                } else {
-                    // check Cow in subscriber's ownership, remove if it does exist.
-                    let cow_in_ownership = self.state.cow_ownership.contains_key(&cow_name)
-                        .await.unwrap_or(false);
-                    if cow_in_ownership {
-                        self.state.cow_ownership.remove(&cow_name).unwrap_or_else(|_| {
-                            panic!("unable to remove Cow from ownership");
-                        });
-                    }
+                // check the feeding window from our local state, root chain will check it on its own clock
+                let cow = self.state.cow_db.get(&cow_name).await
+                    .unwrap_or_else(|_| {
+                        panic!("unable to get Cow DB Result");
+                    }).unwrap_or_else(|| {
+                    panic!("{:?} is not exist", cow_name);
+                });
+                let feeding_status = self.state.get_cow_status(&cow, self.runtime.system_time());
+                if feeding_status != FeedingStatus::OnTime {
+                    panic!("{:?} is not in its on time window, {:?}", cow_name, feeding_status);
                 }
+
+                // send KeeperFeed message to root chain
+                let message = Message::KeeperFeed { owner, cow_name };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            Message::BuyFailure { cow_data, cow_buy_params } => {
-                log::info!("Message::BuyFailure");
-                // Message::BuyFailure not being tracked
-                // Even if it does, bouncing message should do nothing.
-                if is_bouncing {
-                    return;
-                }
+            CowOperation::TakeLoan { owner, cow_names, amount } => {
+                log::info!("CowOperation::TakeLoan");
+                // check authentication
+                self.check_authentication(owner);
 
-                // credit balance due to failure to BuyCow
-                self.state.credit(cow_buy_params.price).await;
-                self.state.buy_notification.push_back(
-                    BuyNotif {
-                        cow_name: cow_buy_params.name,
-                        is_success: false,
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // check collateral & loan size, root chain will check it again
+                let system_time = self.runtime.system_time();
+                let cows = self.state
+                    .check_loan_collateral(owner, &cow_names, system_time)
+                    .await
+                    .unwrap_or_else(|reason| {
+                        panic!("{}", reason);
                     });
+                let max_loan = self.state.get_max_loan(cows, system_time).await;
+                if amount.eq(&Amount::ZERO) || amount.gt(&max_loan) {
+                    panic!("loan amount must be more than 0, and at most {}", max_loan);
+                }
 
-                // save CowData that we failed to buy to local state
-                let cow_name = cow_data.name.clone();
-                self.state.create_new_or_update_cow_data(cow_name, cow_data).await;
+                // send TakeLoan message to root chain
+                let message = Message::TakeLoan { owner, cow_names, amount };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            Message::FeedSuccess { cow_data } => {
-                log::info!("Message::FeedSuccess");
-                // Message::FeedSuccess not being tracked
-                // Even if it does, bouncing message should do nothing.
-                if is_bouncing {
-                    return;
-                }
+            CowOperation::RepayLoan { owner, loan_id } => {
+                log::info!("CowOperation::RepayLoan");
+                // check authentication
+                self.check_authentication(owner);
 
-                // check if CowData belong to us
-                let cow_name = cow_data.name.clone();
-                let is_my_cow = self.state
-                    .is_cow_exist_in_db_and_ownership(cow_name.clone()).await;
-                if is_my_cow {
-                    // check if the cow is actually belong to us
-                    let owner_id = self.state.app_data.get().owner;
-                    if owner_id.ne(&cow_data.owner) {
-                        self.state.cow_ownership.remove(&cow_data.name).unwrap_or_else(|_| {
-                            panic!("unable to remove Cow from ownership");
-                        });
-                    }
-                    // do nothing if all check passed
-                    return;
+                // get our loan
+                let loan = self.state.get_loan(loan_id).await.unwrap_or_else(|| {
+                    panic!("unable to find loan {}", loan_id);
+                });
+                if loan.owner.ne(&owner) {
+                    panic!("loan {} is not yours to repay", loan_id);
                 }
 
-                // else, save data to db
-                self.state.create_new_or_update_cow_data(cow_name, cow_data).await;
-            }
-            Message::SellFailure { cow_name, reason } => {
-                log::info!("Message::SellFailure");
-                // Message::SellFailure not being tracked
-                // Even if it does, bouncing message should do nothing.
-                if is_bouncing {
-                    return;
-                }
+                // debit owner balance to pay the principal & interest
+                let loan_report = self.state.get_loan_report(loan, self.runtime.system_time()).await;
+                let payment = loan_report.amount_due;
+                self.state.debit(payment).await;
 
-                self.state.sell_notification.push_back(SellNotif {
-                    cow_name,
-                    is_success: false,
-                    failure_reason: reason,
-                })
+                // send RepayLoan message to root chain
+                let message = Message::RepayLoan { owner, loan_id, payment };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
             }
-            Message::SellSuccess { cow_name, cow_owner, payment } => {
-                log::info!("Message::SellSuccess");
-                // Message::SellSuccess not being tracked
-                // Even if it does, bouncing message should do nothing.
-                if is_bouncing {
-                    return;
-                }
+            CowOperation::BuyInsurance { owner, cow_name } => {
+                log::info!("CowOperation::BuyInsurance");
+                // check authentication
+                self.check_authentication(owner);
 
-                // update Cow Ownership & Sell Notification only on Seller's local state
-                let owner = self.state.app_data.get().owner;
-                if owner.eq(&cow_owner) {
-                    // remove Cow from ownership
-                    self.state.cow_ownership.remove(&cow_name).unwrap_or_else(|_| {
-                        panic!("unable to remove Cow from ownership");
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // check the cow can be insured, root chain will check it again
+                let system_time = self.runtime.system_time();
+                let cow = self.state
+                    .check_cow_insurable(owner, &cow_name, system_time)
+                    .await
+                    .unwrap_or_else(|reason| {
+                        panic!("{}", reason);
                     });
 
-                    // credit balance to receive payment for the cow
-                    self.state.credit(payment).await;
+                // debit owner balance to pay the premium
+                let insurance_quote = self.state.get_insurance_quote(cow, system_time).await;
+                let payment = insurance_quote.premium;
+                self.state.debit(payment).await;
 
-                    // push sell notification
-                    self.state.sell_notification.push_back(SellNotif {
-                        cow_name: cow_name.clone(),
-                        is_success: true,
-                        failure_reason: String::from(""),
-                    });
-                }
+                // send BuyInsurance message to root chain
+                let message = Message::BuyInsurance { owner, cow_name, payment };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::UpgradeFarm { owner } => {
+                log::info!("CowOperation::UpgradeFarm");
+                // check authentication
+                self.check_authentication(owner);
 
-                // remove Cow from DB
-                self.state.cow_db.remove(&cow_name).unwrap_or_else(|_| {
-                    panic!("unable to remove Cow from DB");
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
                 });
-            }
-        }
-    }
 
-    async fn store(mut self) {
-        self.state.save().await.expect("Failed to save state");
-    }
-}
+                // check the farm can be upgraded, root chain will check it again
+                let level = self.state.get_farm_level(owner).await + 1;
+                let payment = self.state.check_farm_upgradable(owner, level).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
 
-/// ------------------------------------------------------------------------------------------
-impl MicroCowContract {
-    fn check_authentication(&mut self, owner: Owner) {
-        assert_eq!(
+                // debit owner balance to pay for the upgrade
+                self.state.debit(payment).await;
+
+                // send UpgradeFarm message to root chain
+                let message = Message::UpgradeFarm { owner, level, payment };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::TreatCow { owner, cow_name } => {
+                log::info!("CowOperation::TreatCow");
+                // check authentication
+                self.check_authentication(owner);
+
+                // make sure the game is not paused
+                self.state.check_game_not_paused().unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                // check the cow can be treated, root chain will check it again
+                let system_time = self.runtime.system_time();
+                self.state
+                    .check_cow_treatable(owner, &cow_name, system_time)
+                    .await
+                    .unwrap_or_else(|reason| {
+                        panic!("{}", reason);
+                    });
+
+                // debit owner balance to pay for the treatment
+                let payment = self.state.get_treatment_price();
+                self.state.debit(payment).await;
+
+                // send TreatCow message to root chain
+                let message = Message::TreatCow { owner, cow_name, payment };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::ClaimInsurance { owner, cow_name } => {
+                log::info!("CowOperation::ClaimInsurance");
+                // check authentication
+                self.check_authentication(owner);
+
+                // check the insured cow has died, root chain will check it again
+                let policy = self.state.get_cow_policy(&cow_name).await.unwrap_or_else(|| {
+                    panic!("{:?} is not insured", cow_name);
+                });
+                self.state
+                    .check_insurance_claimable(&policy, self.runtime.system_time())
+                    .await
+                    .unwrap_or_else(|reason| {
+                        panic!("{}", reason);
+                    });
+
+                // send ClaimInsurance message to root chain
+                let message = Message::ClaimInsurance { owner, cow_name };
+                self.runtime
+                    .prepare_message(message)
+                    .with_authentication()
+                    .with_tracking()
+                    .send_to(self.runtime.application_parameters().root_chain_id);
+            }
+            CowOperation::BuryDeadCows => {
+                log::info!("CowOperation::BuryDeadCows");
+                // get all of my cow
+                let my_cows = self.state.get_my_cows().await;
+
+                // filter cow who has died
+                let system_time = self.runtime.system_time();
+                for cow in my_cows.into_iter() {
+                    let feeding_status = self.state.get_cow_status(&cow, system_time);
+                    if feeding_status == FeedingStatus::Dead {
+                        // cow last fed time is more than 24 hours, therefore it has died
+                        // remove cow from DB
+                        self.state.cow_db.remove(&cow.name).unwrap_or_else(|_| {
+                            panic!("unable to remove Cow from DB");
+                        });
+                        self.state.cow_ownership.remove(&cow.name).unwrap_or_else(|_| {
+                            panic!("unable to remove Cow from ownership");
+                        });
+                    }
+                }
+            }
+            CowOperation::AdminTopUpTreasury { owner, amount } => {
+                log::info!("CowOperation::AdminTopUpTreasury");
+                self.check_admin(owner);
+
+                self.state.credit(amount).await;
+                self.broadcast(Message::TreasuryToppedUp { amount });
+            }
+            CowOperation::AdminSetCowPrice { owner, cow_breed, price } => {
+                log::info!("CowOperation::AdminSetCowPrice");
+                self.check_admin(owner);
+
+                self.state.set_base_price(cow_breed, price);
+                self.reprice_breed(cow_breed).await;
+                let game_config = self.state.get_game_config();
+                self.broadcast(Message::GameConfigUpdated { game_config });
+            }
+            CowOperation::AdminSetFeedingConfig { owner, feeding_config } => {
+                log::info!("CowOperation::AdminSetFeedingConfig");
+                self.check_admin(owner);
+
+                self.state.set_feeding_config(feeding_config);
+                let game_config = self.state.get_game_config();
+                self.broadcast(Message::GameConfigUpdated { game_config });
+            }
+            CowOperation::AdminRenameCow { owner, cow_name, new_name } => {
+                log::info!("CowOperation::AdminRenameCow");
+                self.check_admin(owner);
+
+                // make sure the cow exist, and the new name is available
+                let is_cow_exist = self.state.cow_db.contains_key(&cow_name).await.unwrap_or(false);
+                if !is_cow_exist {
+                    panic!("{:?} is not exist", cow_name);
+                }
+                self.state.check_cow_name_available(&new_name).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+
+                self.state.rename_cow(cow_name.clone(), new_name.clone()).await;
+                self.broadcast(Message::CowRenamed { cow_name, new_name });
+            }
+            CowOperation::AdminRemoveCow { owner, cow_name } => {
+                log::info!("CowOperation::AdminRemoveCow");
+                self.check_admin(owner);
+
+                // get CowData from DB
+                let cow = self.state.cow_db.get(&cow_name).await
+                    .unwrap_or_else(|_| {
+                        panic!("unable to get Cow DB Result");
+                    }).unwrap_or_else(|| {
+                    panic!("{:?} is not exist", cow_name);
+                });
+
+                // dead cow still gets its policy paid, a living cow's policy is closed with the cow
+                // the loan it's pledged to is written off, since the collateral is gone
+                let reason = format!("{:?} is removed by admin", cow_name);
+                let is_dead = self.state.get_cow_status(&cow, self.runtime.system_time()) == FeedingStatus::Dead;
+                if is_dead {
+                    self.settle_cow_insurance(&cow_name).await;
+                } else {
+                    self.close_cow_policy_on_root(&cow_name, reason.clone()).await;
+                }
+                self.close_cow_loan_on_root(&cow_name, reason).await;
+
+                self.state.remove_cow(cow_name.clone()).await;
+                let in_ownership = self.state.cow_ownership.contains_key(&cow_name).await.unwrap_or(false);
+                if in_ownership {
+                    self.state.cow_ownership.remove(&cow_name).unwrap_or_else(|_| {
+                        panic!("unable to remove Cow from ownership");
+                    });
+                }
+                self.state.record_cow_removed();
+                self.state.sub_player_herd_size(cow.owner).await;
+                self.close_keeper_budget_on_root(&cow_name).await;
+                self.broadcast(Message::CowRemoved { cow_name });
+            }
+            CowOperation::AdminAddAdmin { owner, admin } => {
+                log::info!("CowOperation::AdminAddAdmin");
+                self.check_admin(owner);
+
+                self.state.add_admin(admin);
+                let admins = self.state.get_admins();
+                self.broadcast(Message::AdminsUpdated { admins });
+            }
+            CowOperation::AdminRemoveAdmin { owner, admin } => {
+                log::info!("CowOperation::AdminRemoveAdmin");
+                self.check_admin(owner);
+
+                self.state.remove_admin(admin);
+                let admins = self.state.get_admins();
+                self.broadcast(Message::AdminsUpdated { admins });
+            }
+            CowOperation::AdminPauseGame { owner, reason } => {
+                log::info!("CowOperation::AdminPauseGame");
+                self.check_admin(owner);
+
+                if self.state.pause_state.get().is_paused {
+                    panic!("Micro Cow is already paused");
+                }
+                let pause_state = PauseState {
+                    is_paused: true,
+                    reason,
+                    paused_at: self.runtime.system_time(),
+                };
+                self.state.pause_state.set(pause_state.clone());
+                self.broadcast(Message::GamePaused { pause_state });
+            }
+            CowOperation::AdminResumeGame { owner } => {
+                log::info!("CowOperation::AdminResumeGame");
+                self.check_admin(owner);
+
+                if !self.state.pause_state.get().is_paused {
+                    panic!("Micro Cow is not paused");
+                }
+                let paused_at = self.state.pause_state.get().paused_at;
+                let resumed_at = self.runtime.system_time();
+                self.state.resume_game(paused_at, resumed_at).await;
+                self.broadcast(Message::GameResumed { paused_at, resumed_at });
+            }
+            CowOperation::AdminSetMinReserveRatio { owner, min_reserve_ratio } => {
+                log::info!("CowOperation::AdminSetMinReserveRatio");
+                self.check_admin(owner);
+
+                self.state.set_min_reserve_ratio(min_reserve_ratio);
+                let min_reserve_ratio = self.state.get_min_reserve_ratio();
+                self.broadcast(Message::ReserveRatioUpdated { min_reserve_ratio });
+            }
+            CowOperation::AdminSetPriceBounds { owner, cow_breed, min_price, max_price } => {
+                log::info!("CowOperation::AdminSetPriceBounds");
+                self.check_admin(owner);
+
+                self.state.set_price_bounds(cow_breed, min_price, max_price);
+                self.reprice_breed(cow_breed).await;
+                let game_config = self.state.get_game_config();
+                self.broadcast(Message::GameConfigUpdated { game_config });
+            }
+            CowOperation::AdminSetPopulationCap { owner, cow_breed, population_cap } => {
+                log::info!("CowOperation::AdminSetPopulationCap");
+                self.check_admin(owner);
+
+                self.state.set_population_cap(cow_breed, population_cap);
+                let breed_supplies = self.state.get_breed_supplies();
+                self.broadcast(Message::BreedSuppliesUpdated { breed_supplies });
+            }
+            CowOperation::AdminReleaseLimitedEdition { owner, cow_breed, total_supply } => {
+                log::info!("CowOperation::AdminReleaseLimitedEdition");
+                self.check_admin(owner);
+
+                self.state.release_limited_edition(cow_breed, total_supply);
+                let breed_supplies = self.state.get_breed_supplies();
+                self.broadcast(Message::BreedSuppliesUpdated { breed_supplies });
+            }
+            CowOperation::AdminScheduleEvent { owner, name, start_time, end_time, modifiers } => {
+                log::info!("CowOperation::AdminScheduleEvent");
+                self.check_admin(owner);
+
+                let system_time = self.runtime.system_time();
+                self.state.schedule_event(name, start_time, end_time, modifiers, system_time);
+                let seasonal_events = self.state.get_seasonal_events();
+                self.broadcast(Message::SeasonalEventsUpdated { seasonal_events });
+            }
+            CowOperation::AdminCancelEvent { owner, event_id } => {
+                log::info!("CowOperation::AdminCancelEvent");
+                self.check_admin(owner);
+
+                self.state.cancel_event(event_id);
+                let seasonal_events = self.state.get_seasonal_events();
+                self.broadcast(Message::SeasonalEventsUpdated { seasonal_events });
+            }
+            CowOperation::AdminSetQuestSchedule { owner, quests, quests_per_day, start_time } => {
+                log::info!("CowOperation::AdminSetQuestSchedule");
+                self.check_admin(owner);
+
+                let start_time = start_time.unwrap_or(self.runtime.system_time());
+                let quest_schedule = self.state.set_quest_schedule(quests, quests_per_day, start_time);
+                self.broadcast(Message::QuestScheduleUpdated { quest_schedule: Some(quest_schedule) });
+            }
+            CowOperation::AdminClearQuestSchedule { owner } => {
+                log::info!("CowOperation::AdminClearQuestSchedule");
+                self.check_admin(owner);
+
+                self.state.save_quest_schedule(None);
+                self.broadcast(Message::QuestScheduleUpdated { quest_schedule: None });
+            }
+            CowOperation::AdminSeizeDefaultedLoans { owner } => {
+                log::info!("CowOperation::AdminSeizeDefaultedLoans");
+                self.check_admin(owner);
+
+                let system_time = self.runtime.system_time();
+                for loan in self.state.get_loans(None).await.into_iter() {
+                    let loan_report = self.state.get_loan_report(loan.clone(), system_time).await;
+                    if loan_report.is_defaulted {
+                        self.seize_loan_on_root(loan).await;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn execute_message(&mut self, _message: Self::Message) {
+        let is_bouncing = self
+            .runtime
+            .message_is_bouncing()
+            .unwrap_or_else(|| {
+                panic!("Message delivery status has to be available when executing a message");
+            });
+
+        let message_id = self
+            .runtime
+            .message_id()
+            .unwrap_or_else(|| {
+                panic!("Message ID has to be available when executing a message");
+            });
+
+        match _message {
+            // ! executed by ROOT chain
+            // ! --------------------------------------------------------------------------------
+            Message::BuyCow { owner, cow_buy_params } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to BuyCow
+                    log::info!("Message::BuyCow - Fail to Buy Cow: {:?}", cow_buy_params.name.clone());
+                    self.state.credit(cow_buy_params.price).await;
+                    self.state.buy_notification.push_back(
+                        BuyNotif {
+                            cow_name: cow_buy_params.name,
+                            is_success: false,
+                            failure_reason: String::from("Failure to buy, operation bounced"),
+                        });
+                    return;
+                }
+
+                log::info!("Message::BuyCow");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // reject while the game is paused, the buyer is refunded on BuyFailure
+                if let Err(reason) = self.state.check_game_not_paused() {
+                    let message = Message::BuyFailure { cow_data: None, cow_buy_params, reason };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+
+                // make sure buyer pays the current price, the buyer is refunded on BuyFailure
+                let cow_price = self.state.get_event_cow_price(cow_buy_params.breed, self.runtime.system_time());
+                if cow_buy_params.price.ne(&cow_price) {
+                    let reason = format!("{:?} price has changed to {}", cow_buy_params.breed, cow_price);
+                    let message = Message::BuyFailure { cow_data: None, cow_buy_params, reason };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // check cow name in DB and available to buy
+                // you can't buy if cow exist and alive
+                let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(
+                    cow_buy_params.name.clone(), self.runtime.system_time(),
+                ).await;
+                if is_cow_alive_and_exist {
+                    let cow_data = self.state.cow_db.get(&cow_buy_params.name.clone()).await
+                        .unwrap_or_else(|_| {
+                            panic!("unable to get Cow DB Result");
+                        }).unwrap_or_else(|| {
+                        panic!("unable to get Cow DB Option");
+                    });
+
+                    let reason = format!("{:?} is not available", cow_buy_params.name);
+                    let message = Message::BuyFailure { cow_data: Some(cow_data), cow_buy_params, reason };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+
+                // make sure the breed supply is not exceeded
+                let system_time = self.runtime.system_time();
+                if let Err(reason) = self.state.check_breed_supply(cow_buy_params.breed, system_time).await {
+                    let message = Message::BuyFailure { cow_data: None, cow_buy_params, reason };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+
+                // make sure there is room in the buyer's barn
+                if let Err(reason) = self.state.check_barn_capacity(owner, self.runtime.system_time()).await {
+                    let message = Message::BuyFailure { cow_data: None, cow_buy_params, reason };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+
+                // mint the new cow for the buyer
+                let new_cow_data = self.mint_cow_on_root(owner, cow_buy_params).await;
+                let cow_breed = new_cow_data.breed;
+                let system_time = self.runtime.system_time();
+
+                // count quest progress & award buying milestones
+                self.state.record_quest_action(owner, QuestAction::BuyCow(cow_breed), system_time).await;
+                self.award_achievement(owner, Achievement::FirstCow).await;
+                let is_breed_collector = self.state.has_achievement(owner, Achievement::BreedCollector).await;
+                if !is_breed_collector && self.state.owns_every_breed(owner, system_time).await {
+                    self.award_achievement(owner, Achievement::BreedCollector).await;
+                }
+
+                // notify to channel subscriber that a BuyCow is success
+                let message = Message::BuySuccess { cow_data: new_cow_data };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
+            }
+            Message::BuyFeed { owner, feed_type, quantity, payment } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to BuyFeed
+                    log::info!("Message::BuyFeed - Fail to Buy Feed: {} {:?}", quantity, feed_type);
+                    self.state.credit(payment).await;
+                    self.state.shop_notification.push_back(ShopNotif {
+                        feed_type,
+                        quantity,
+                        amount: payment,
+                        is_success: false,
+                        failure_reason: String::from("Failure to buy feed, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::BuyFeed");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // add the feed to root inventory & refund the excess payment
+                let message = match self.buy_feed_on_root(owner, feed_type, quantity, payment).await {
+                    Ok((amount, feed_inventory)) => Message::FeedBought {
+                        feed_type,
+                        quantity,
+                        amount,
+                        refund: payment.saturating_sub(amount),
+                        feed_inventory,
+                    },
+                    Err(reason) => Message::FeedShopFailure { feed_type, quantity, reason, refund: payment },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::FeedCow { owner, cow_data, feed_type } => {
+                log::info!("Message::FeedCow");
+                // Message::FeedCow not being tracked
+                // Even if it does, bouncing message should do nothing.
+                if is_bouncing {
+                    return;
+                }
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // reject while the game is paused
+                if let Err(reason) = self.state.check_game_not_paused() {
+                    self.reject_fed_cows(owner, vec![cow_data], reason, message_id.chain_id).await;
+                    return;
+                }
+
+                // owner feeds its own cow, a caretaker needs an active grant from the owner
+                self.seize_defaulted_loan_on_root(&cow_data.name).await;
+                let cow_owner = match self.state.check_cow_feeder(&cow_data.name, owner, system_time).await {
+                    Ok(cow_owner) => cow_owner,
+                    Err(reason) => {
+                        self.reject_fed_cows(owner, vec![cow_data], reason, message_id.chain_id).await;
+                        return;
+                    }
+                };
+
+                // feed the cow that root chain has, on its own clock, the player chain only tells which cow
+                let cow_data = match self.get_root_fed_cow(&cow_data.name, feed_type).await {
+                    Ok(root_cow_data) => root_cow_data,
+                    Err(reason) => {
+                        self.reject_fed_cows(owner, vec![cow_data], reason, message_id.chain_id).await;
+                        return;
+                    }
+                };
+
+                // make sure the feed item was really used
+                if let Err(reason) = self.state.use_feed(owner, feed_type, 1).await {
+                    self.reject_fed_cows(owner, vec![cow_data], reason, message_id.chain_id).await;
+                    return;
+                }
+
+                // save data to db
+                self.save_fed_cow_on_root(cow_data.clone(), owner).await;
+
+                // record the caretaker feeding, and let the owner know who fed the cow
+                if cow_owner.ne(&owner) {
+                    self.state.record_caretaker_feeding(cow_owner, owner, system_time).await;
+                    if let Some(player) = self.state.get_player(cow_owner).await {
+                        let message = Message::CaretakerFed { caretaker: owner, cow_data: cow_data.clone() };
+                        self.runtime
+                            .prepare_message(message)
+                            .send_to(player.chain_id);
+                    }
+                }
+
+                // notify to channel subscriber that a FeedCow is success
+                let message = Message::FeedSuccess { cow_data };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
+            }
+            Message::SellCow { owner, cow_name } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::SellCow - Fail to Sell Cow: {:?}", cow_name);
+                    self.state.sell_notification.push_back(SellNotif {
+                        cow_name,
+                        is_success: false,
+                        failure_reason: String::from("Failure to sell, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::SellCow");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // reject while the game is paused
+                if let Err(reason) = self.state.check_game_not_paused() {
+                    let message = Message::SellFailure { cow_name, reason };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+
+                // get CowData from DB, only a living & grown cow of the seller can be sold
+                let cow = match self.check_cow_sellable_on_root(&cow_name, owner).await {
+                    Ok(cow) => cow,
+                    Err(reason) => {
+                        let message = Message::SellFailure { cow_name, reason };
+                        self.runtime
+                            .prepare_message(message)
+                            .send_to(message_id.chain_id);
+                        return;
+                    }
+                };
+
+                // sell the cow & check contract balance
+                let cow_selling_price = match self.sell_cow_on_root(cow.clone(), owner).await {
+                    Ok(cow_selling_price) => cow_selling_price,
+                    Err(reason) => {
+                        let message = Message::SellFailure { cow_name, reason };
+                        self.runtime
+                            .prepare_message(message)
+                            .send_to(message_id.chain_id);
+                        return;
+                    }
+                };
+
+                // notify to channel subscriber that a SellCow is success
+                let message = Message::SellSuccess {
+                    cow_name,
+                    cow_owner: cow.owner,
+                    payment: cow_selling_price,
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
+            }
+            Message::FeedCows { owner, cows, feed_type } => {
+                log::info!("Message::FeedCows");
+                if is_bouncing {
+                    return;
+                }
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // reject while the game is paused
+                if let Err(reason) = self.state.check_game_not_paused() {
+                    self.reject_fed_cows(owner, cows, reason, message_id.chain_id).await;
+                    return;
+                }
+
+                // only the owner's cows can be fed in a batch, the others are rejected one by one
+                let mut accepted_cows = Vec::new();
+                let mut rejected_cows = Vec::new();
+                for cow_data in cows.into_iter() {
+                    if let Err(reason) = self.check_cow_owner_on_root(&cow_data.name, owner).await {
+                        rejected_cows.push((cow_data, reason));
+                        continue;
+                    }
+                    match self.get_root_fed_cow(&cow_data.name, feed_type).await {
+                        Ok(root_cow_data) => accepted_cows.push(root_cow_data),
+                        Err(reason) => rejected_cows.push((cow_data, reason)),
+                    }
+                }
+
+                // make sure a feed item was really used for every accepted cow
+                if let Err(reason) = self.state.use_feed(owner, feed_type, accepted_cows.len() as u64).await {
+                    self.reject_fed_cows(owner, accepted_cows, reason, message_id.chain_id).await;
+                    accepted_cows = Vec::new();
+                }
+
+                // rejected cows are sent back after the feed is used, so the player gets the final feed inventory
+                for (cow_data, reason) in rejected_cows.into_iter() {
+                    self.reject_fed_cows(owner, vec![cow_data], reason, message_id.chain_id).await;
+                }
+                if accepted_cows.is_empty() {
+                    return;
+                }
+                let cows = accepted_cows;
+
+                // save data to db
+                for cow_data in cows.iter() {
+                    self.save_fed_cow_on_root(cow_data.clone(), owner).await;
+                }
+
+                // notify to channel subscriber that a FeedCows is success
+                let message = Message::FeedCowsSuccess { cows };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
+            }
+            Message::SellCows { owner, cow_names } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::SellCows - Fail to Sell Cows: {:?}", cow_names);
+                    for cow_name in cow_names.into_iter() {
+                        self.state.sell_notification.push_back(SellNotif {
+                            cow_name,
+                            is_success: false,
+                            failure_reason: String::from("Failure to sell, operation bounced"),
+                        });
+                    }
+                    return;
+                }
+
+                log::info!("Message::SellCows");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // reject while the game is paused
+                if let Err(reason) = self.state.check_game_not_paused() {
+                    let failures = cow_names.into_iter()
+                        .map(|cow_name| SellNotif {
+                            cow_name,
+                            is_success: false,
+                            failure_reason: reason.clone(),
+                        })
+                        .collect();
+                    let message = Message::SellCowsFailure { failures };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+
+                // every cow get its own selling result, failing one cow won't fail the others
+                let mut sales = Vec::new();
+                let mut failures = Vec::new();
+                for cow_name in cow_names.into_iter() {
+                    let result = match self.check_cow_sellable_on_root(&cow_name, owner).await {
+                        Ok(cow) => self.sell_cow_on_root(cow, owner).await,
+                        Err(reason) => Err(reason),
+                    };
+                    match result {
+                        Ok(payment) => sales.push(CowSale { cow_name, cow_owner: owner, payment }),
+                        Err(reason) => failures.push(SellNotif {
+                            cow_name,
+                            is_success: false,
+                            failure_reason: reason,
+                        }),
+                    }
+                }
+
+                // notify the seller about cows that failed to be sold
+                if !failures.is_empty() {
+                    let message = Message::SellCowsFailure { failures };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                }
+
+                // notify to channel subscriber that a SellCows is success
+                if !sales.is_empty() {
+                    let message = Message::SellCowsSuccess { sales };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
+                }
+            }
+            Message::ClaimDailyReward { owner } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::ClaimDailyReward - Fail to Claim Daily Reward");
+                    self.state.reward_notification.push_back(RewardNotif {
+                        is_success: false,
+                        failure_reason: String::from("Failure to claim daily reward, operation bounced"),
+                        ..RewardNotif::default()
+                    });
+                    return;
+                }
+
+                log::info!("Message::ClaimDailyReward");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // pay the reward & notify the player
+                let message = match self.claim_daily_reward_on_root(owner).await {
+                    Ok((daily_reward, amount)) => Message::DailyRewardClaimed { daily_reward, amount },
+                    Err(reason) => Message::DailyRewardFailure { reason },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::ClaimQuestReward { owner, quest_index } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::ClaimQuestReward - Fail to Claim Quest Reward: {}", quest_index);
+                    self.state.quest_notification.push_back(QuestNotif {
+                        quest_index,
+                        is_success: false,
+                        failure_reason: String::from("Failure to claim quest reward, operation bounced"),
+                        ..QuestNotif::default()
+                    });
+                    return;
+                }
+
+                log::info!("Message::ClaimQuestReward");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // pay the reward & notify the player
+                let message = match self.claim_quest_reward_on_root(owner, quest_index).await {
+                    Ok((quest_progress, amount)) => Message::QuestRewardPaid { quest_index, quest_progress, amount },
+                    Err(reason) => Message::QuestFailure { quest_index, reason },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::RenameCow { owner, cow_name, new_name, payment } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to RenameCow
+                    log::info!("Message::RenameCow - Fail to Rename Cow: {:?}", cow_name);
+                    self.state.credit(payment).await;
+                    self.state.rename_notification.push_back(RenameNotif {
+                        cow_name,
+                        new_name,
+                        amount: payment,
+                        is_success: false,
+                        failure_reason: String::from("Failure to rename, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::RenameCow");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // rename the cow & refund the excess payment
+                let message = match self.rename_cow_on_root(owner, cow_name.clone(), new_name.clone(), payment).await {
+                    Ok(amount) => {
+                        // notify to channel subscriber that the cow has a new name
+                        self.broadcast(Message::CowRenamed { cow_name: cow_name.clone(), new_name: new_name.clone() });
+                        Message::RenameSuccess { cow_name, new_name, amount, refund: payment.saturating_sub(amount) }
+                    }
+                    Err(reason) => Message::RenameFailure { cow_name, new_name, reason, refund: payment },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::CreateCoop { owner, name, members, feed_rule, trade_rule } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::CreateCoop - Fail to Create Cooperative: {:?}", name);
+                    self.push_coop_failure(0, CoopAction::Create, String::from(""), Amount::ZERO,
+                        String::from("Failure to create cooperative, operation bounced"));
+                    return;
+                }
+
+                log::info!("Message::CreateCoop");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let message = match self.create_coop_on_root(owner, name, members, feed_rule, trade_rule).await {
+                    Ok(coop_id) => Message::CoopSuccess {
+                        coop_id,
+                        action: CoopAction::Create,
+                        cow_name: String::from(""),
+                        amount: Amount::ZERO,
+                    },
+                    Err(reason) => Message::CoopFailure {
+                        coop_id: 0,
+                        action: CoopAction::Create,
+                        cow_name: String::from(""),
+                        reason,
+                        refund: Amount::ZERO,
+                    },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::DepositToCoop { owner, coop_id, amount } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to DepositToCoop
+                    log::info!("Message::DepositToCoop - Fail to Deposit: {}", amount);
+                    self.state.credit(amount).await;
+                    self.push_coop_failure(coop_id, CoopAction::Deposit, String::from(""), amount,
+                        String::from("Failure to deposit, operation bounced"));
+                    return;
+                }
+
+                log::info!("Message::DepositToCoop");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let message = match self.deposit_to_coop_on_root(owner, coop_id, amount).await {
+                    Ok(()) => Message::CoopSuccess {
+                        coop_id,
+                        action: CoopAction::Deposit,
+                        cow_name: String::from(""),
+                        amount,
+                    },
+                    Err(reason) => Message::CoopFailure {
+                        coop_id,
+                        action: CoopAction::Deposit,
+                        cow_name: String::from(""),
+                        reason,
+                        refund: amount,
+                    },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::CoopBuyCow { owner, coop_id, cow_buy_params } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::CoopBuyCow - Fail to Buy Cow: {:?}", cow_buy_params.name);
+                    self.push_coop_failure(coop_id, CoopAction::BuyCow, cow_buy_params.name, Amount::ZERO,
+                        String::from("Failure to buy, operation bounced"));
+                    return;
+                }
+
+                log::info!("Message::CoopBuyCow");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let cow_name = cow_buy_params.name.clone();
+                let message = match self.coop_buy_cow_on_root(owner, coop_id, cow_buy_params).await {
+                    Ok((cow_data, amount)) => {
+                        // notify to channel subscriber that a cooperative cow is bought
+                        self.broadcast(Message::BuySuccess { cow_data });
+                        Message::CoopSuccess { coop_id, action: CoopAction::BuyCow, cow_name, amount }
+                    }
+                    Err(reason) => Message::CoopFailure {
+                        coop_id,
+                        action: CoopAction::BuyCow,
+                        cow_name,
+                        reason,
+                        refund: Amount::ZERO,
+                    },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::CoopFeedCow { owner, coop_id, cow_name, feed_type } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::CoopFeedCow - Fail to Feed Cow: {:?}", cow_name);
+                    self.push_coop_failure(coop_id, CoopAction::FeedCow, cow_name, Amount::ZERO,
+                        String::from("Failure to feed, operation bounced"));
+                    return;
+                }
+
+                log::info!("Message::CoopFeedCow");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let message = match self.coop_feed_cow_on_root(owner, coop_id, cow_name.clone(), feed_type).await {
+                    Ok((cow_data, amount)) => {
+                        // notify to channel subscriber that a cooperative cow is fed
+                        self.broadcast(Message::FeedSuccess { cow_data });
+                        Message::CoopSuccess { coop_id, action: CoopAction::FeedCow, cow_name, amount }
+                    }
+                    Err(reason) => Message::CoopFailure {
+                        coop_id,
+                        action: CoopAction::FeedCow,
+                        cow_name,
+                        reason,
+                        refund: Amount::ZERO,
+                    },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::CoopSellCow { owner, coop_id, cow_name } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::CoopSellCow - Fail to Sell Cow: {:?}", cow_name);
+                    self.push_coop_failure(coop_id, CoopAction::SellCow, cow_name, Amount::ZERO,
+                        String::from("Failure to sell, operation bounced"));
+                    return;
+                }
+
+                log::info!("Message::CoopSellCow");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let message = match self.coop_sell_cow_on_root(owner, coop_id, cow_name.clone()).await {
+                    Ok((herd_owner, amount)) => {
+                        // notify to channel subscriber that a cooperative cow is sold
+                        self.broadcast(Message::SellSuccess {
+                            cow_name: cow_name.clone(),
+                            cow_owner: herd_owner,
+                            payment: amount,
+                        });
+                        Message::CoopSuccess { coop_id, action: CoopAction::SellCow, cow_name, amount }
+                    }
+                    Err(reason) => Message::CoopFailure {
+                        coop_id,
+                        action: CoopAction::SellCow,
+                        cow_name,
+                        reason,
+                        refund: Amount::ZERO,
+                    },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::DissolveCoop { owner, coop_id } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::DissolveCoop - Fail to Dissolve Cooperative: {}", coop_id);
+                    self.push_coop_failure(coop_id, CoopAction::Dissolve, String::from(""), Amount::ZERO,
+                        String::from("Failure to dissolve cooperative, operation bounced"));
+                    return;
+                }
+
+                log::info!("Message::DissolveCoop");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let message = match self.dissolve_coop_on_root(owner, coop_id).await {
+                    Ok(amount) => Message::CoopSuccess {
+                        coop_id,
+                        action: CoopAction::Dissolve,
+                        cow_name: String::from(""),
+                        amount,
+                    },
+                    Err(reason) => Message::CoopFailure {
+                        coop_id,
+                        action: CoopAction::Dissolve,
+                        cow_name: String::from(""),
+                        reason,
+                        refund: Amount::ZERO,
+                    },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::GrantCaretaker { owner, caretaker, cow_names, expires_at } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::GrantCaretaker - Fail to Grant Caretaker: {:?}", caretaker);
+                    self.state.caretaker_notification.push_back(CaretakerNotif {
+                        owner,
+                        caretaker,
+                        action: CaretakerAction::Grant,
+                        cow_name: String::from(""),
+                        is_success: false,
+                        failure_reason: String::from("Failure to grant caretaker, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::GrantCaretaker");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let grantable = self.state
+                    .check_caretaker_grantable(owner, caretaker, &cow_names, expires_at, system_time).await;
+                if let Err(reason) = grantable {
+                    let message = Message::CaretakerFailure { caretaker, action: CaretakerAction::Grant, reason };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+
+                // save the grant, and send a copy to both owner & caretaker
+                let grant = CaretakerGrant {
+                    owner,
+                    caretaker,
+                    cow_names,
+                    granted_at: system_time,
+                    expires_at,
+                    feed_count: 0,
+                    last_fed_at: None,
+                };
+                self.state.save_caretaker_grant(grant.clone(), system_time).await;
+                let message = move || Message::CaretakerGranted { grant: grant.clone() };
+                self.send_to_owner_and_caretaker(message, message_id.chain_id, caretaker).await;
+            }
+            Message::RevokeCaretaker { owner, caretaker } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::RevokeCaretaker - Fail to Revoke Caretaker: {:?}", caretaker);
+                    self.state.caretaker_notification.push_back(CaretakerNotif {
+                        owner,
+                        caretaker,
+                        action: CaretakerAction::Revoke,
+                        cow_name: String::from(""),
+                        is_success: false,
+                        failure_reason: String::from("Failure to revoke caretaker, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::RevokeCaretaker");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                if let Err(reason) = self.state.remove_caretaker_grant(owner, caretaker).await {
+                    let message = Message::CaretakerFailure { caretaker, action: CaretakerAction::Revoke, reason };
+                    self.runtime
+                        .prepare_message(message)
+                        .send_to(message_id.chain_id);
+                    return;
+                }
+                let message = move || Message::CaretakerRevoked { owner, caretaker };
+                self.send_to_owner_and_caretaker(message, message_id.chain_id, caretaker).await;
+            }
+            Message::FundKeeperBudget { owner, cow_name, feed_type, amount } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to FundKeeperBudget
+                    log::info!("Message::FundKeeperBudget - Fail to Fund Keeper Budget: {:?}", cow_name);
+                    self.state.credit(amount).await;
+                    self.state.keeper_notification.push_back(KeeperNotif {
+                        cow_name,
+                        action: KeeperAction::Fund,
+                        amount,
+                        is_success: false,
+                        failure_reason: String::from("Failure to fund keeper budget, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::FundKeeperBudget");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let message = match self.fund_keeper_budget_on_root(owner, cow_name.clone(), feed_type, amount).await {
+                    Ok(keeper_budget) => Message::KeeperBudgetUpdated { keeper_budget, amount },
+                    Err(reason) => Message::KeeperFailure { cow_name, action: KeeperAction::Fund, reason, refund: amount },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::WithdrawKeeperBudget { owner, cow_name } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::WithdrawKeeperBudget - Fail to Withdraw Keeper Budget: {:?}", cow_name);
+                    self.state.keeper_notification.push_back(KeeperNotif {
+                        cow_name,
+                        action: KeeperAction::Withdraw,
+                        amount: Amount::ZERO,
+                        is_success: false,
+                        failure_reason: String::from("Failure to withdraw keeper budget, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::WithdrawKeeperBudget");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // budget is withdrawn even while the game is paused, nothing is fed from it
+                let message = match self.state.get_keeper_budget(&cow_name).await {
+                    Some(keeper_budget) if keeper_budget.owner == owner => {
+                        self.state.remove_keeper_budget(&cow_name);
+                        Message::KeeperBudgetClosed { cow_name, refund: keeper_budget.balance }
+                    }
+                    _ => Message::KeeperFailure {
+                        cow_name: cow_name.clone(),
+                        action: KeeperAction::Withdraw,
+                        reason: format!("{:?} has no keeper budget of yours", cow_name),
+                        refund: Amount::ZERO,
+                    },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::KeeperFeed { owner, cow_name } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::KeeperFeed - Fail to Feed Cow: {:?}", cow_name);
+                    self.state.keeper_notification.push_back(KeeperNotif {
+                        cow_name,
+                        action: KeeperAction::Fee,
+                        amount: Amount::ZERO,
+                        is_success: false,
+                        failure_reason: String::from("Failure to feed, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::KeeperFeed");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                let message = match self.keeper_feed_on_root(owner, cow_name.clone()).await {
+                    Ok((cow_data, keeper_budget, feeding_cost)) => {
+                        // notify to channel subscriber that the cow is fed,
+                        // and let the owner follow the root chain CowData & budget
+                        self.broadcast(Message::FeedSuccess { cow_data: cow_data.clone() });
+                        if let Some(player) = self.state.get_player(keeper_budget.owner).await {
+                            let message = Message::KeeperFed { cow_data, keeper_budget, amount: feeding_cost };
+                            self.runtime
+                                .prepare_message(message)
+                                .send_to(player.chain_id);
+                        }
+                        Message::KeeperFeePaid { cow_name, fee: Amount::from_tokens(KEEPER_FEE) }
+                    }
+                    Err(reason) => Message::KeeperFailure {
+                        cow_name,
+                        action: KeeperAction::Fee,
+                        reason,
+                        refund: Amount::ZERO,
+                    },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::TakeLoan { owner, cow_names, amount } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::TakeLoan - Fail to Take Loan: {:?}", cow_names);
+                    self.state.loan_notification.push_back(LoanNotif {
+                        amount,
+                        is_success: false,
+                        failure_reason: String::from("Failure to take loan, operation bounced"),
+                        ..LoanNotif::default()
+                    });
+                    return;
+                }
+
+                log::info!("Message::TakeLoan");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // lend the tokens & notify the borrower
+                let message = match self.open_loan_on_root(owner, cow_names, amount).await {
+                    Ok(loan) => Message::LoanApproved { loan },
+                    Err(reason) => Message::LoanFailure { loan_id: None, reason, refund: Amount::ZERO },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::RepayLoan { owner, loan_id, payment } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to RepayLoan
+                    log::info!("Message::RepayLoan - Fail to Repay Loan: {}", loan_id);
+                    self.state.credit(payment).await;
+                    self.state.loan_notification.push_back(LoanNotif {
+                        loan_id,
+                        amount: payment,
+                        is_success: false,
+                        failure_reason: String::from("Failure to repay loan, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::RepayLoan");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // close the loan & refund the excess payment
+                let message = match self.repay_loan_on_root(owner, loan_id, payment).await {
+                    Ok(refund) => Message::LoanRepaid { loan_id, refund },
+                    Err(reason) => Message::LoanFailure { loan_id: Some(loan_id), reason, refund: payment },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::BuyInsurance { owner, cow_name, payment } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to BuyInsurance
+                    log::info!("Message::BuyInsurance - Fail to Buy Insurance: {:?}", cow_name);
+                    self.state.credit(payment).await;
+                    self.state.insurance_notification.push_back(InsuranceNotif {
+                        cow_name,
+                        amount: payment,
+                        is_success: false,
+                        failure_reason: String::from("Failure to buy insurance, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::BuyInsurance");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // issue the policy & refund the excess payment
+                let message = match self.issue_policy_on_root(owner, cow_name.clone(), payment).await {
+                    Ok((policy, refund)) => Message::InsuranceIssued { policy, refund },
+                    Err(reason) => Message::InsuranceFailure { cow_name, reason, refund: payment },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::UpgradeFarm { owner, level, payment } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to UpgradeFarm
+                    log::info!("Message::UpgradeFarm - Fail to Upgrade Farm: {}", level);
+                    self.state.credit(payment).await;
+                    self.state.farm_notification.push_back(FarmNotif {
+                        level,
+                        amount: payment,
+                        is_success: false,
+                        failure_reason: String::from("Failure to upgrade farm, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::UpgradeFarm");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // upgrade the farm & refund the excess payment
+                let message = match self.upgrade_farm_on_root(owner, level, payment).await {
+                    Ok(amount) => Message::FarmUpgraded { level, amount, refund: payment.saturating_sub(amount) },
+                    Err(reason) => Message::FarmUpgradeFailure { level, reason, refund: payment },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::TreatCow { owner, cow_name, payment } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // credit balance due to failure to TreatCow
+                    log::info!("Message::TreatCow - Fail to Treat Cow: {:?}", cow_name);
+                    self.state.credit(payment).await;
+                    self.state.health_notification.push_back(HealthNotif {
+                        cow_name,
+                        amount: payment,
+                        is_success: false,
+                        failure_reason: String::from("Failure to treat, operation bounced"),
+                    });
+                    return;
+                }
+
+                log::info!("Message::TreatCow");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // cure the cow & refund the excess payment
+                match self.treat_cow_on_root(owner, cow_name.clone(), payment).await {
+                    Ok((cow_data, amount)) => {
+                        let message = Message::CowTreated {
+                            cow_data: cow_data.clone(),
+                            amount,
+                            refund: payment.saturating_sub(amount),
+                        };
+                        self.runtime
+                            .prepare_message(message)
+                            .send_to(message_id.chain_id);
+
+                        // notify to channel subscriber that the cow is healthy again
+                        self.broadcast(Message::CowHealthUpdated { cow_data });
+                    }
+                    Err(reason) => {
+                        let message = Message::TreatmentFailure { cow_name, reason, refund: payment };
+                        self.runtime
+                            .prepare_message(message)
+                            .send_to(message_id.chain_id);
+                    }
+                }
+            }
+            Message::ClaimInsurance { owner, cow_name } => {
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    log::info!("Message::ClaimInsurance - Fail to Claim Insurance: {:?}", cow_name);
+                    self.state.insurance_notification.push_back(InsuranceNotif {
+                        cow_name,
+                        is_success: false,
+                        failure_reason: String::from("Failure to claim insurance, operation bounced"),
+                        ..InsuranceNotif::default()
+                    });
+                    return;
+                }
+
+                log::info!("Message::ClaimInsurance");
+
+                // check authentication
+                self.check_authentication(owner);
+
+                // update player registry
+                let system_time = self.runtime.system_time();
+                self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+
+                // pay the coverage & notify the policy owner
+                let message = match self.claim_insurance_on_root(owner, cow_name.clone()).await {
+                    Ok((policy, payout)) => Message::InsurancePaid { policy, payout },
+                    Err(reason) => Message::InsuranceFailure { cow_name, reason, refund: Amount::ZERO },
+                };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(message_id.chain_id);
+            }
+            Message::Subscribe => {
+                log::info!("Message::Subscribe");
+                if is_bouncing {
+                    // ? BOUNCING parts executed by USER chain
+                    // nothing happens for now
+                    return;
+                }
+
+                // register the Chain ID from message as subscriber for MICRO_COW_CHANNEL
+                self.runtime.subscribe(
+                    message_id.chain_id,
+                    ChannelName::from(MICRO_COW_CHANNEL.to_vec()),
+                );
+
+                // register the player who sent the subscription
+                if let Some(owner) = self.runtime.authenticated_signer() {
+                    let system_time = self.runtime.system_time();
+                    self.state.record_player_activity(owner, message_id.chain_id, system_time).await;
+                }
+
+                // channel only delivers what's broadcast from now on, so catch up the new subscriber
+                self.send_game_snapshot(message_id.chain_id);
+            }
+            // ! executed by USER chain
+            // ! --------------------------------------------------------------------------------
+            Message::BuySuccess { cow_data } => {
+                log::info!("Message::BuySuccess");
+                // Message::BuySuccess not being tracked
+                // Even if it does, bouncing message should do nothing.
+                if is_bouncing {
+                    return;
+                }
+
+                // save new CowData to local state in all Micro Cow channel subscriber
+                let cow_name = cow_data.name.clone();
+                self.state.create_new_or_update_cow_data(cow_name.clone(), cow_data.clone()).await;
+
+
+                // update Cow Ownership & Buy Notification only on Buyer's local state
+                let owner = self.state.app_data.get().owner;
+                if owner.eq(&cow_data.owner) {
+                    self.state.update_cow_ownership(cow_name.clone()).await;
+                    self.state.buy_notification.push_back(BuyNotif {
+                        cow_name: cow_name.clone(),
+                        is_success: true,
+                        failure_reason: String::from(""),
+                    });
+                } else {
+                    // check Cow in subscriber's ownership, remove if it does exist.
+                    let cow_in_ownership = self.state.cow_ownership.contains_key(&cow_name)
+                        .await.unwrap_or(false);
+                    if cow_in_ownership {
+                        self.state.cow_ownership.remove(&cow_name).unwrap_or_else(|_| {
+                            panic!("unable to remove Cow from ownership");
+                        });
+                    }
+                }
+            }
+            Message::BuyFailure { cow_data, cow_buy_params, reason } => {
+                log::info!("Message::BuyFailure");
+                // Message::BuyFailure not being tracked
+                // Even if it does, bouncing message should do nothing.
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance due to failure to BuyCow
+                self.state.credit(cow_buy_params.price).await;
+                self.state.buy_notification.push_back(
+                    BuyNotif {
+                        cow_name: cow_buy_params.name,
+                        is_success: false,
+                        failure_reason: reason,
+                    });
+
+                // save CowData that we failed to buy to local state
+                if let Some(cow_data) = cow_data {
+                    let cow_name = cow_data.name.clone();
+                    self.state.create_new_or_update_cow_data(cow_name, cow_data).await;
+                }
+            }
+            Message::FeedSuccess { cow_data } => {
+                log::info!("Message::FeedSuccess");
+                // Message::FeedSuccess not being tracked
+                // Even if it does, bouncing message should do nothing.
+                if is_bouncing {
+                    return;
+                }
+
+                self.save_fed_cow(cow_data).await;
+            }
+            Message::FeedCowsSuccess { cows } => {
+                log::info!("Message::FeedCowsSuccess");
+                if is_bouncing {
+                    return;
+                }
+
+                for cow_data in cows.into_iter() {
+                    self.save_fed_cow(cow_data).await;
+                }
+            }
+            Message::FeedFailure { cows, reason, feed_inventory } => {
+                log::info!("Message::FeedFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // restore CowData & feed inventory from root chain,
+                // and notify player that the feeding is a failure
+                let owner = self.state.app_data.get().owner;
+                self.state.save_feed_inventory(owner, feed_inventory);
+                for cow_data in cows.into_iter() {
+                    self.state.feed_notification.push_back(FeedNotif {
+                        cow_name: cow_data.name.clone(),
+                        is_success: false,
+                        failure_reason: reason.clone(),
+                    });
+                    self.state.create_new_or_update_cow_data(cow_data.name.clone(), cow_data).await;
+                }
+            }
+            Message::FeedBought { feed_type, quantity, amount, refund, feed_inventory } => {
+                log::info!("Message::FeedBought");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the excess payment, and follow root inventory
+                self.state.credit(refund).await;
+                let owner = self.state.app_data.get().owner;
+                self.state.save_feed_inventory(owner, feed_inventory);
+                self.state.shop_notification.push_back(ShopNotif {
+                    feed_type,
+                    quantity,
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::FeedShopFailure { feed_type, quantity, reason, refund } => {
+                log::info!("Message::FeedShopFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the payment back
+                self.state.credit(refund).await;
+                self.state.shop_notification.push_back(ShopNotif {
+                    feed_type,
+                    quantity,
+                    amount: refund,
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::SellFailure { cow_name, reason } => {
+                log::info!("Message::SellFailure");
+                // Message::SellFailure not being tracked
+                // Even if it does, bouncing message should do nothing.
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.sell_notification.push_back(SellNotif {
+                    cow_name,
+                    is_success: false,
+                    failure_reason: reason,
+                })
+            }
+            Message::SellSuccess { cow_name, cow_owner, payment } => {
+                log::info!("Message::SellSuccess");
+                // Message::SellSuccess not being tracked
+                // Even if it does, bouncing message should do nothing.
+                if is_bouncing {
+                    return;
+                }
+
+                self.complete_cow_sale(cow_name, cow_owner, payment).await;
+            }
+            Message::SellCowsFailure { failures } => {
+                log::info!("Message::SellCowsFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                for failure in failures.into_iter() {
+                    self.state.sell_notification.push_back(failure);
+                }
+            }
+            Message::SellCowsSuccess { sales } => {
+                log::info!("Message::SellCowsSuccess");
+                if is_bouncing {
+                    return;
+                }
+
+                for sale in sales.into_iter() {
+                    self.complete_cow_sale(sale.cow_name, sale.cow_owner, sale.payment).await;
+                }
+            }
+            Message::DailyRewardClaimed { daily_reward, amount } => {
+                log::info!("Message::DailyRewardClaimed");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the reward
+                self.state.credit(amount).await;
+                self.state.reward_notification.push_back(RewardNotif {
+                    amount,
+                    streak: daily_reward.streak,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+                self.state.save_daily_reward(daily_reward);
+            }
+            Message::DailyRewardFailure { reason } => {
+                log::info!("Message::DailyRewardFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.reward_notification.push_back(RewardNotif {
+                    is_success: false,
+                    failure_reason: reason,
+                    ..RewardNotif::default()
+                });
+            }
+            Message::LoanApproved { loan } => {
+                log::info!("Message::LoanApproved");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the loan
+                self.state.credit(loan.principal).await;
+                self.state.loan_notification.push_back(LoanNotif {
+                    loan_id: loan.id,
+                    amount: loan.principal,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+                self.state.save_loan(loan);
+            }
+            Message::LoanRepaid { loan_id, refund } => {
+                log::info!("Message::LoanRepaid");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the excess payment
+                self.state.credit(refund).await;
+                if let Some(loan) = self.state.get_loan(loan_id).await {
+                    self.state.close_loan(&loan);
+                }
+                self.state.loan_notification.push_back(LoanNotif {
+                    loan_id,
+                    amount: refund,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::LoanFailure { loan_id, reason, refund } => {
+                log::info!("Message::LoanFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the payment back
+                self.state.credit(refund).await;
+                self.state.loan_notification.push_back(LoanNotif {
+                    loan_id: loan_id.unwrap_or_default(),
+                    amount: refund,
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::LoanSeized { loan } => {
+                log::info!("Message::LoanSeized");
+                if is_bouncing {
+                    return;
+                }
+
+                // remove the seized cows, and the loan only exist on the borrower's chain
+                let owner = self.state.app_data.get().owner;
+                for cow_name in loan.cow_names.iter() {
+                    self.state.remove_cow(cow_name.clone()).await;
+                    if owner.eq(&loan.owner) {
+                        self.state.cow_ownership.remove(cow_name).unwrap_or_else(|_| {
+                            panic!("unable to remove Cow from ownership");
+                        });
+                    }
+                }
+                if owner.eq(&loan.owner) {
+                    self.state.close_loan(&loan);
+                    self.state.loan_notification.push_back(LoanNotif {
+                        loan_id: loan.id,
+                        amount: loan.principal,
+                        is_success: false,
+                        failure_reason: format!("Loan {} has defaulted, collateral is seized", loan.id),
+                    });
+                }
+            }
+            Message::LoanClosed { loan, reason } => {
+                log::info!("Message::LoanClosed");
+                if is_bouncing {
+                    return;
+                }
+
+                // the rest of the collateral is released, and the loan doesn't have to be repaid
+                if self.state.get_loan(loan.id).await.is_some() {
+                    self.state.close_loan(&loan);
+                }
+                self.state.loan_notification.push_back(LoanNotif {
+                    loan_id: loan.id,
+                    amount: loan.principal,
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::InsuranceIssued { policy, refund } => {
+                log::info!("Message::InsuranceIssued");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the excess payment
+                self.state.credit(refund).await;
+                self.state.insurance_notification.push_back(InsuranceNotif {
+                    cow_name: policy.cow_name.clone(),
+                    amount: policy.premium,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+                self.state.save_policy(policy);
+            }
+            Message::InsurancePaid { policy, payout } => {
+                log::info!("Message::InsurancePaid");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the coverage
+                self.state.credit(payout).await;
+                if self.state.get_policy(policy.id).await.is_some() {
+                    self.state.close_policy(&policy);
+                }
+                self.state.insurance_notification.push_back(InsuranceNotif {
+                    cow_name: policy.cow_name,
+                    amount: payout,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::InsuranceFailure { cow_name, reason, refund } => {
+                log::info!("Message::InsuranceFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the payment back
+                self.state.credit(refund).await;
+                self.state.insurance_notification.push_back(InsuranceNotif {
+                    cow_name,
+                    amount: refund,
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::FarmUpgraded { level, amount, refund } => {
+                log::info!("Message::FarmUpgraded");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the excess payment
+                self.state.credit(refund).await;
+                let owner = self.state.app_data.get().owner;
+                self.state.save_farm_level(owner, level);
+                self.state.farm_notification.push_back(FarmNotif {
+                    level,
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::FarmUpgradeFailure { level, reason, refund } => {
+                log::info!("Message::FarmUpgradeFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the payment back
+                self.state.credit(refund).await;
+                self.state.farm_notification.push_back(FarmNotif {
+                    level,
+                    amount: refund,
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::CowTreated { cow_data, amount, refund } => {
+                log::info!("Message::CowTreated");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the excess payment
+                self.state.credit(refund).await;
+                self.state.health_notification.push_back(HealthNotif {
+                    cow_name: cow_data.name.clone(),
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+                self.state.create_new_or_update_cow_data(cow_data.name.clone(), cow_data).await;
+            }
+            Message::TreatmentFailure { cow_name, reason, refund } => {
+                log::info!("Message::TreatmentFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the payment back
+                self.state.credit(refund).await;
+                self.state.health_notification.push_back(HealthNotif {
+                    cow_name,
+                    amount: refund,
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::AchievementAwarded { player_achievement } => {
+                log::info!("Message::AchievementAwarded");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the bonus
+                self.state.credit(player_achievement.bonus).await;
+                self.state.achievement_notification.push_back(AchievementNotif {
+                    achievement: player_achievement.achievement,
+                    bonus: player_achievement.bonus,
+                });
+                self.state.add_achievement(player_achievement).await;
+            }
+            Message::QuestRewardPaid { quest_index, quest_progress, amount } => {
+                log::info!("Message::QuestRewardPaid");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the reward
+                self.state.credit(amount).await;
+                let owner = self.state.app_data.get().owner;
+                self.state.save_quest_progress(owner, quest_progress).await;
+                self.state.quest_notification.push_back(QuestNotif {
+                    quest_index,
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::QuestFailure { quest_index, reason } => {
+                log::info!("Message::QuestFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.quest_notification.push_back(QuestNotif {
+                    quest_index,
+                    is_success: false,
+                    failure_reason: reason,
+                    ..QuestNotif::default()
+                });
+            }
+            Message::RenameSuccess { cow_name, new_name, amount, refund } => {
+                log::info!("Message::RenameSuccess");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the excess payment
+                // the cow may have been renamed already by Message::CowRenamed, renaming again does nothing
+                self.state.credit(refund).await;
+                self.state.rename_cow(cow_name.clone(), new_name.clone()).await;
+                self.state.rename_notification.push_back(RenameNotif {
+                    cow_name,
+                    new_name,
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::RenameFailure { cow_name, new_name, reason, refund } => {
+                log::info!("Message::RenameFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the payment back
+                self.state.credit(refund).await;
+                self.state.rename_notification.push_back(RenameNotif {
+                    cow_name,
+                    new_name,
+                    amount: refund,
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::CoopSuccess { coop_id, action, cow_name, amount } => {
+                log::info!("Message::CoopSuccess");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.coop_notification.push_back(CoopNotif {
+                    coop_id,
+                    action,
+                    cow_name,
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::CoopFailure { coop_id, action, cow_name, reason, refund } => {
+                log::info!("Message::CoopFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the deposit back
+                self.state.credit(refund).await;
+                self.push_coop_failure(coop_id, action, cow_name, refund, reason);
+            }
+            Message::CoopPayout { coop_id, action, cow_name, amount } => {
+                log::info!("Message::CoopPayout");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive our share
+                self.state.credit(amount).await;
+                self.state.coop_notification.push_back(CoopNotif {
+                    coop_id,
+                    action,
+                    cow_name,
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::CaretakerGranted { grant } => {
+                log::info!("Message::CaretakerGranted");
+                if is_bouncing {
+                    return;
+                }
+
+                // keep a copy of the grant, on both owner & caretaker chains
+                let system_time = self.runtime.system_time();
+                self.state.save_caretaker_grant(grant.clone(), system_time).await;
+                self.state.caretaker_notification.push_back(CaretakerNotif {
+                    owner: grant.owner,
+                    caretaker: grant.caretaker,
+                    action: CaretakerAction::Grant,
+                    cow_name: String::from(""),
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::CaretakerRevoked { owner, caretaker } => {
+                log::info!("Message::CaretakerRevoked");
+                if is_bouncing {
+                    return;
+                }
+
+                // the local copy may be gone already, once it has expired
+                let _ = self.state.remove_caretaker_grant(owner, caretaker).await;
+                self.state.caretaker_notification.push_back(CaretakerNotif {
+                    owner,
+                    caretaker,
+                    action: CaretakerAction::Revoke,
+                    cow_name: String::from(""),
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::CaretakerFailure { caretaker, action, reason } => {
+                log::info!("Message::CaretakerFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                let owner = self.state.app_data.get().owner;
+                self.state.caretaker_notification.push_back(CaretakerNotif {
+                    owner,
+                    caretaker,
+                    action,
+                    cow_name: String::from(""),
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::CaretakerFed { caretaker, cow_data } => {
+                log::info!("Message::CaretakerFed");
+                if is_bouncing {
+                    return;
+                }
+
+                // owner chain didn't feed the cow itself, so it follows the root chain CowData
+                let owner = cow_data.owner;
+                let system_time = self.runtime.system_time();
+                self.state.record_caretaker_feeding(owner, caretaker, system_time).await;
+                self.state.caretaker_notification.push_back(CaretakerNotif {
+                    owner,
+                    caretaker,
+                    action: CaretakerAction::Feed,
+                    cow_name: cow_data.name.clone(),
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+                self.state.create_new_or_update_cow_data(cow_data.name.clone(), cow_data).await;
+            }
+            Message::KeeperBudgetUpdated { keeper_budget, amount } => {
+                log::info!("Message::KeeperBudgetUpdated");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.keeper_notification.push_back(KeeperNotif {
+                    cow_name: keeper_budget.cow_name.clone(),
+                    action: KeeperAction::Fund,
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+                self.state.save_keeper_budget(keeper_budget);
+            }
+            Message::KeeperBudgetClosed { cow_name, refund } => {
+                log::info!("Message::KeeperBudgetClosed");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the rest of the budget
+                self.state.credit(refund).await;
+                self.state.remove_keeper_budget(&cow_name);
+                self.state.keeper_notification.push_back(KeeperNotif {
+                    cow_name,
+                    action: KeeperAction::Withdraw,
+                    amount: refund,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::KeeperFed { cow_data, keeper_budget, amount } => {
+                log::info!("Message::KeeperFed");
+                if is_bouncing {
+                    return;
+                }
+
+                // owner chain didn't feed the cow itself, so it follows the root chain CowData
+                self.state.keeper_notification.push_back(KeeperNotif {
+                    cow_name: cow_data.name.clone(),
+                    action: KeeperAction::Feed,
+                    amount,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+                self.state.save_keeper_budget(keeper_budget);
+                self.state.create_new_or_update_cow_data(cow_data.name.clone(), cow_data).await;
+            }
+            Message::KeeperFeePaid { cow_name, fee } => {
+                log::info!("Message::KeeperFeePaid");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the keeper fee
+                self.state.credit(fee).await;
+                self.state.keeper_notification.push_back(KeeperNotif {
+                    cow_name,
+                    action: KeeperAction::Fee,
+                    amount: fee,
+                    is_success: true,
+                    failure_reason: String::from(""),
+                });
+            }
+            Message::KeeperFailure { cow_name, action, reason, refund } => {
+                log::info!("Message::KeeperFailure");
+                if is_bouncing {
+                    return;
+                }
+
+                // credit balance to receive the budget back
+                self.state.credit(refund).await;
+                self.state.keeper_notification.push_back(KeeperNotif {
+                    cow_name,
+                    action,
+                    amount: refund,
+                    is_success: false,
+                    failure_reason: reason,
+                });
+            }
+            Message::CowHealthUpdated { cow_data } => {
+                log::info!("Message::CowHealthUpdated");
+                if is_bouncing {
+                    return;
+                }
+
+                self.save_fed_cow(cow_data).await;
+            }
+            Message::InsuranceClosed { policy, reason } => {
+                log::info!("Message::InsuranceClosed");
+                if is_bouncing {
+                    return;
+                }
+
+                if self.state.get_policy(policy.id).await.is_some() {
+                    self.state.close_policy(&policy);
+                }
+                self.state.insurance_notification.push_back(InsuranceNotif {
+                    cow_name: policy.cow_name,
+                    is_success: false,
+                    failure_reason: reason,
+                    ..InsuranceNotif::default()
+                });
+            }
+            Message::TreasuryToppedUp { amount } => {
+                log::info!("Message::TreasuryToppedUp: {}", amount);
+            }
+            Message::GameConfigUpdated { game_config } => {
+                log::info!("Message::GameConfigUpdated");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.game_config.set(Some(game_config));
+            }
+            Message::ReserveRatioUpdated { min_reserve_ratio } => {
+                log::info!("Message::ReserveRatioUpdated");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.min_reserve_ratio.set(Some(min_reserve_ratio));
+            }
+            Message::BreedSuppliesUpdated { breed_supplies } => {
+                log::info!("Message::BreedSuppliesUpdated");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.breed_supplies.set(breed_supplies);
+            }
+            Message::CowRenamed { cow_name, new_name } => {
+                log::info!("Message::CowRenamed");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.rename_cow(cow_name, new_name).await;
+            }
+            Message::CowRemoved { cow_name } => {
+                log::info!("Message::CowRemoved");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.remove_cow(cow_name.clone()).await;
+                self.state.cow_ownership.remove(&cow_name).unwrap_or_else(|_| {
+                    panic!("unable to remove Cow from ownership");
+                });
+            }
+            Message::AdminsUpdated { admins } => {
+                log::info!("Message::AdminsUpdated");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.admins.set(admins);
+            }
+            Message::GamePaused { pause_state } => {
+                log::info!("Message::GamePaused");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.pause_state.set(pause_state);
+            }
+            Message::GameResumed { paused_at, resumed_at } => {
+                log::info!("Message::GameResumed");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.resume_game(paused_at, resumed_at).await;
+            }
+            Message::SeasonalEventsUpdated { seasonal_events } => {
+                log::info!("Message::SeasonalEventsUpdated");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.seasonal_events.set(seasonal_events);
+            }
+            Message::QuestScheduleUpdated { quest_schedule } => {
+                log::info!("Message::QuestScheduleUpdated");
+                if is_bouncing {
+                    return;
+                }
+
+                self.state.save_quest_schedule(quest_schedule);
+            }
+        }
+    }
+
+    async fn store(mut self) {
+        self.state.save().await.expect("Failed to save state");
+    }
+}
+
+/// ------------------------------------------------------------------------------------------
+impl MicroCowContract {
+    fn check_authentication(&mut self, owner: Owner) {
+        assert_eq!(
             self.runtime.authenticated_signer(),
             Some(owner),
             "Incorrect owner authentication"
         )
     }
 
-    fn check_root_invocation(&mut self) {
-        assert_ne!(
-            self.runtime.chain_id(),
-            self.runtime.application_parameters().root_chain_id,
-            "Root are not allowed to play"
-        )
+    fn check_root_invocation(&mut self) {
+        assert_ne!(
+            self.runtime.chain_id(),
+            self.runtime.application_parameters().root_chain_id,
+            "Root are not allowed to play"
+        )
+    }
+
+    fn check_admin_invocation(&mut self) {
+        assert_eq!(
+            self.runtime.chain_id(),
+            self.runtime.application_parameters().root_chain_id,
+            "Admin operation only allowed on Root"
+        )
+    }
+
+    fn check_admin(&mut self, owner: Owner) {
+        self.check_authentication(owner);
+        assert!(self.state.is_admin(owner), "{:?} is not an admin", owner);
+    }
+
+    async fn random_cow_gender(&mut self) -> CowGender {
+        // produce seed array using system time
+        let timestamp = self.runtime.system_time().to_string();
+        let concatenated_timestamp = format!("{}{}{}", timestamp, timestamp, timestamp);
+        let timestamp_str = truncate(concatenated_timestamp.as_str(), 32);
+        let seed_array = <[u8; 32]>::try_from(timestamp_str.as_bytes()).unwrap();
+
+        // get random value using provided seed
+        let buff = &mut [0];
+        custom_getrandom(buff, seed_array).unwrap_or_else(|_| {
+            panic!("failed random fill");
+        });
+        let val = buff.first().unwrap_or_else(|| {
+            panic!("failed to get random value");
+        });
+
+        // check if random value is Even or Odd
+        if val % 2 == 0 {
+            return CowGender::Female;
+        }
+        CowGender::Male
+    }
+
+    async fn random_roll(&mut self, cow: &CowData) -> u64 {
+        // produce seed array using system time, the cow, and a nonce,
+        // so cows fed in the same block don't share the same roll
+        let timestamp = self.runtime.system_time().micros().to_le_bytes();
+        let random_nonce = self.state.next_random_nonce().to_le_bytes();
+        let seed_array = mix_seed(&[&timestamp, &random_nonce, cow.id.as_bytes(), cow.name.as_bytes()]);
+
+        // get random value between 0 and 100%, using 2 digit decimal precision
+        let buff = &mut [0; 2];
+        seeded_getrandom(buff, seed_array).unwrap_or_else(|_| {
+            panic!("failed random fill");
+        });
+        (u16::from_le_bytes(*buff) as u64) % (PRECISION_100_PERCENT as u64)
+    }
+
+    async fn feed_cow(&mut self, cow_name: String, feed_type: FeedType) -> Result<CowData, String> {
+        // make sure the cow is existing both on local DB and in ownership
+        // if not exist, probably the cow isn't yours to feed
+        let is_cow_exist = self.state
+            .is_cow_exist_in_db_and_ownership(cow_name.clone()).await;
+        if !is_cow_exist {
+            return Err(format!("{:?} is not exist", cow_name));
+        }
+
+        // check cow name in DB and available to feed
+        // you can't feed if cow already died
+        let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(
+            cow_name.clone(), self.runtime.system_time(),
+        ).await;
+        if !is_cow_alive_and_exist {
+            return Err(format!("you can't feed {:?}", cow_name));
+        }
+
+        // get CowData from DB
+        let cow = self.state.cow_db.get(&cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            }).unwrap_or_else(|| {
+            panic!("unable to get Cow DB Option");
+        });
+        let previous_on_time = cow.feeding_stats.on_time;
+        let cow = self.get_fed_cow(cow, feed_type).await?;
+
+        // count quest progress
+        let owner = cow.owner;
+        let system_time = self.runtime.system_time();
+        self.state.record_quest_action(owner, QuestAction::FeedCow, system_time).await;
+        if cow.feeding_stats.on_time > previous_on_time {
+            self.state.record_quest_action(owner, QuestAction::FeedOnTime, system_time).await;
+        }
+
+        // save data to db
+        self.state.create_new_or_update_cow_data(cow_name, cow.clone()).await;
+        Ok(cow)
+    }
+
+    async fn get_fed_cow(&mut self, mut cow: CowData, feed_type: FeedType) -> Result<CowData, String> {
+        // find out feeding status
+        let system_time = self.runtime.system_time();
+        let feeding_status = self.state.get_cow_status(&cow, system_time);
+
+        // calculate feeding stats, with the feed type effect
+        match feeding_status {
+            FeedingStatus::Full => return Err(format!("{:?} still full", cow.name)),
+            FeedingStatus::Dead => return Err(format!("you can't feed {:?}", cow.name)),
+            _ => {}
+        }
+        let fed_feeding_stats = self.state.get_fed_feeding_stats(cow.feeding_stats.clone(), feeding_status, feed_type);
+        let event_bonus = self.state.get_event_feeding_bonus(&cow.feeding_stats, &fed_feeding_stats, system_time);
+        cow.event_bonus = cow.event_bonus.saturating_add(event_bonus);
+        cow.feeding_stats = fed_feeding_stats;
+
+        // healthy cow fed LATE or FORGOT may fall ill, the chance grows with its feeding history
+        let is_fed_late = feeding_status == FeedingStatus::Late || feeding_status == FeedingStatus::Forgot;
+        if is_fed_late && cow.health == CowHealth::Healthy {
+            let sickness_chance = self.state.get_sickness_chance(&cow.feeding_stats);
+            if self.random_roll(&cow).await < sickness_chance {
+                cow.health = CowHealth::Sick { sick_since: system_time };
+            }
+        }
+
+        // update cow data
+        cow.last_fed_time = system_time;
+        Ok(cow)
+    }
+
+    async fn get_root_fed_cow(&mut self, cow_name: &String, feed_type: FeedType) -> Result<CowData, String> {
+        let cow = self.state.cow_db.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        self.get_fed_cow(cow, feed_type).await
+    }
+
+    async fn feed_cows(&mut self, owner: Owner, cow_names: Vec<String>, feed_type: FeedType) {
+        // every cow get its own feeding result, failing one cow won't fail the others
+        // cows that are left once the feed runs out are not fed
+        let mut fed_cows = Vec::new();
+        for cow_name in cow_names.into_iter() {
+            let is_feed_left = self.state.get_feed_inventory(owner).await.count(feed_type) > 0;
+            if !is_feed_left {
+                self.state.feed_notification.push_back(FeedNotif {
+                    cow_name,
+                    is_success: false,
+                    failure_reason: format!("not enough {:?}", feed_type),
+                });
+                continue;
+            }
+            let feeding = self.feed_cow(cow_name.clone(), feed_type).await;
+            if feeding.is_ok() {
+                self.state.use_feed(owner, feed_type, 1).await.unwrap_or_else(|reason| {
+                    panic!("{}", reason);
+                });
+            }
+            match feeding {
+                Ok(cow) => {
+                    self.state.feed_notification.push_back(FeedNotif {
+                        cow_name,
+                        is_success: true,
+                        failure_reason: String::from(""),
+                    });
+                    fed_cows.push(cow);
+                }
+                Err(reason) => self.state.feed_notification.push_back(FeedNotif {
+                    cow_name,
+                    is_success: false,
+                    failure_reason: reason,
+                }),
+            }
+        }
+        if fed_cows.is_empty() {
+            return;
+        }
+
+        // send a single FeedCows message to root chain
+        let message = Message::FeedCows { owner, cows: fed_cows, feed_type };
+        self.runtime
+            .prepare_message(message)
+            .with_authentication()
+            .send_to(self.runtime.application_parameters().root_chain_id);
+    }
+
+    async fn check_cow_sellable(&mut self, cow_name: String, cow_born_time: Timestamp) -> Result<(), String> {
+        // make sure the cow is existing both on local DB and in ownership
+        // if not exist, probably the cow isn't yours to sell
+        let is_cow_exist = self.state
+            .is_cow_exist_in_db_and_ownership(cow_name.clone()).await;
+        if !is_cow_exist {
+            return Err(format!("{:?} is not exist", cow_name));
+        }
+
+        // check cow name in DB and available to sell
+        // you can't sell if cow already died
+        let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(
+            cow_name.clone(), self.runtime.system_time(),
+        ).await;
+        if !is_cow_alive_and_exist {
+            return Err(format!("you can't sell {:?}", cow_name));
+        }
+
+        // pledged cow can't be sold until the loan is repaid
+        if self.state.is_cow_pledged(&cow_name).await {
+            return Err(format!("{:?} is pledged as loan collateral", cow_name));
+        }
+
+        // check if cow is underage
+        let system_time = self.runtime.system_time();
+        let is_cow_underage = self.state.is_cow_underage(cow_born_time, system_time).await;
+        if is_cow_underage {
+            return Err(format!("{:?} is too young to be sold", cow_name));
+        }
+        Ok(())
+    }
+
+    async fn check_cow_owner_on_root(&mut self, cow_name: &String, owner: Owner) -> Result<CowData, String> {
+        // root chain DB is the source of truth of cow ownership,
+        // player chain ownership may be stale once a dead cow is bought by someone else
+        self.seize_defaulted_loan_on_root(cow_name).await;
+        let cow = self.state.cow_db.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        if cow.owner.ne(&owner) {
+            return Err(format!("{:?} is not yours", cow_name));
+        }
+        Ok(cow)
+    }
+
+    async fn check_cow_sellable_on_root(&mut self, cow_name: &String, owner: Owner) -> Result<CowData, String> {
+        let cow = self.check_cow_owner_on_root(cow_name, owner).await?;
+
+        // you can't sell if cow already died
+        let system_time = self.runtime.system_time();
+        if self.state.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
+            return Err(format!("you can't sell {:?}", cow_name));
+        }
+
+        // check if cow is underage
+        if self.state.is_cow_underage(cow.born_time, system_time).await {
+            return Err(format!("{:?} is too young to be sold", cow_name));
+        }
+        Ok(cow)
+    }
+
+    async fn check_cow_renamable(&mut self, cow_name: String, new_name: &String) -> Result<(), String> {
+        // make sure the cow is existing both on local DB and in ownership
+        // if not exist, probably the cow isn't yours to rename
+        let is_cow_exist = self.state
+            .is_cow_exist_in_db_and_ownership(cow_name.clone()).await;
+        if !is_cow_exist {
+            return Err(format!("{:?} is not exist", cow_name));
+        }
+
+        // you can't rename if cow already died
+        let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(
+            cow_name.clone(), self.runtime.system_time(),
+        ).await;
+        if !is_cow_alive_and_exist {
+            return Err(format!("you can't rename {:?}", cow_name));
+        }
+        self.state.check_cow_name_available(new_name).await
+    }
+
+    async fn save_fed_cow_on_root(&mut self, cow_data: CowData, feeder: Owner) {
+        // record feeding stats changes
+        let cow_name = cow_data.name.clone();
+        let previous_feeding_stats = self.state.cow_db.get(&cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .map(|cow| cow.feeding_stats)
+            .unwrap_or_default();
+        let on_time_feedings = cow_data.feeding_stats.on_time.saturating_sub(previous_feeding_stats.on_time);
+        self.state.record_cow_fed(previous_feeding_stats, cow_data.feeding_stats.clone()).await;
+
+        // count quest progress for whoever fed the cow
+        let owner = feeder;
+        let system_time = self.runtime.system_time();
+        self.state.record_quest_action(owner, QuestAction::FeedCow, system_time).await;
+        if on_time_feedings > 0 {
+            self.state.record_quest_action(owner, QuestAction::FeedOnTime, system_time).await;
+        }
+
+        // award the feeding milestone
+        let total_on_time_feedings = self.state.record_on_time_feedings(owner, on_time_feedings).await;
+        if total_on_time_feedings >= ON_TIME_FEEDER_COUNT {
+            self.award_achievement(owner, Achievement::OnTimeFeeder).await;
+        }
+
+        // save data to db
+        self.state.create_new_or_update_cow_data(cow_name, cow_data).await;
+    }
+
+    async fn reject_fed_cows(&mut self, owner: Owner, cows: Vec<CowData>, reason: String, chain_id: ChainId) {
+        // send back CowData & feed inventory that root chain has, so the player can restore it
+        let mut root_cows = Vec::new();
+        for cow_data in cows.into_iter() {
+            let root_cow = self.state.cow_db.get(&cow_data.name).await
+                .unwrap_or_else(|_| {
+                    panic!("unable to get Cow DB Result");
+                });
+            if let Some(root_cow) = root_cow {
+                root_cows.push(root_cow);
+            }
+        }
+        let feed_inventory = self.state.get_feed_inventory(owner).await;
+        let message = Message::FeedFailure { cows: root_cows, reason, feed_inventory };
+        self.runtime
+            .prepare_message(message)
+            .send_to(chain_id);
+    }
+
+    async fn mint_cow_on_root(&mut self, owner: Owner, cow_buy_params: CowBuyParams) -> CowData {
+        // dead cow that is insured gets its policy paid
+        self.settle_cow_insurance(&cow_buy_params.name).await;
+
+        // dead cow pledged as collateral defaults its loan
+        if let Some(loan) = self.state.get_cow_loan(&cow_buy_params.name).await {
+            self.seize_loan_on_root(loan).await;
+        }
+
+        // cow name still in DB means the previous cow has died
+        let dead_cow = self.state.cow_db.get(&cow_buy_params.name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            });
+        let is_replacing_dead_cow = dead_cow.is_some();
+
+        // dead cow's keeper budget goes back to its owner, so the new owner can fund the cow
+        self.close_keeper_budget_on_root(&cow_buy_params.name).await;
+
+        // new cow data.
+        let new_cow_data = CowData {
+            id: cow_buy_params.id,
+            name: cow_buy_params.name.clone(),
+            breed: cow_buy_params.breed,
+            gender: cow_buy_params.gender,
+            born_time: self.runtime.system_time(),
+            last_fed_time: self.runtime.system_time(),
+            feeding_stats: FeedingStats::new(),
+            owner,
+            health: CowHealth::Healthy,
+            event_bonus: 0,
+        };
+        self.state.create_new_or_update_cow_data(cow_buy_params.name, new_cow_data.clone()).await;
+
+        // credit balance to receive payment for the cow
+        self.state.credit(cow_buy_params.price).await;
+        self.state.record_cow_bought(cow_buy_params.price, is_replacing_dead_cow).await;
+
+        // the new owner's herd grows, the dead cow's owner no longer counts it
+        self.state.add_player_herd_size(owner).await;
+        if let Some(dead_cow) = dead_cow {
+            self.state.sub_player_herd_size(dead_cow.owner).await;
+        }
+
+        // update breed supply & price from the market
+        let cow_breed = new_cow_data.breed;
+        let system_time = self.runtime.system_time();
+        self.state.record_breed_minted(cow_breed);
+        self.state.record_market_buy(cow_breed, system_time);
+        if self.reprice_breed(cow_breed).await {
+            let game_config = self.state.get_game_config();
+            self.broadcast(Message::GameConfigUpdated { game_config });
+        }
+        new_cow_data
+    }
+
+    async fn sell_cow_on_root(&mut self, cow: CowData, seller: Owner) -> Result<Amount, String> {
+        // pledged cow can't be sold until the loan is repaid
+        if self.state.is_cow_pledged(&cow.name).await {
+            return Err(format!("{:?} is pledged as loan collateral", cow.name));
+        }
+
+        // calculate cow selling price, scaled by the treasury reserve health
+        let system_time = self.runtime.system_time();
+        let cow_base_price = self.state.get_cow_price(cow.breed);
+        let cow_appraisal_price = self.state.cow_sell_value(cow.clone(), system_time).await;
+        let treasury_health = self.state.get_block_treasury_health(system_time).await;
+        let cow_selling_price = self.state.scale_payout(cow_appraisal_price, &treasury_health);
+
+        // check contract balance
+        let contract_balance = self.state.app_data.get().balance;
+        if contract_balance.lt(&cow_selling_price) {
+            return Err(String::from("Insufficient contract balance"));
+        }
+
+        // remove Cow from DB
+        self.state.cow_db.remove(&cow.name).unwrap_or_else(|_| {
+            panic!("unable to remove Cow from DB");
+        });
+
+        // debit contract balance to pay for the cow
+        self.state.debit(cow_selling_price).await;
+        self.state.record_cow_sold(cow_selling_price).await;
+        self.state.sub_player_herd_size(cow.owner).await;
+
+        // sold cow is no longer insured, and the rest of its keeper budget goes back to its owner
+        if let Some(policy) = self.state.get_cow_policy(&cow.name).await {
+            self.state.close_policy(&policy);
+        }
+        self.close_keeper_budget_on_root(&cow.name).await;
+
+        // count quest progress & award the selling milestone for whoever sold the cow
+        self.state.record_quest_action(seller, QuestAction::SellCow, system_time).await;
+        if cow_selling_price > cow_base_price.saturating_mul(SHREWD_SELLER_MULTIPLIER) {
+            self.award_achievement(seller, Achievement::ShrewdSeller).await;
+        }
+
+        // update breed price from the market
+        let system_time = self.runtime.system_time();
+        self.state.record_market_sell(cow.breed, system_time);
+        if self.reprice_breed(cow.breed).await {
+            let game_config = self.state.get_game_config();
+            self.broadcast(Message::GameConfigUpdated { game_config });
+        }
+        Ok(cow_selling_price)
+    }
+
+    async fn claim_daily_reward_on_root(&mut self, owner: Owner) -> Result<(DailyReward, Amount), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        // check the cooldown from the last claim
+        let system_time = self.runtime.system_time();
+        let daily_reward = self.state.get_daily_reward(owner).await;
+        self.state.check_daily_reward_claimable(daily_reward.as_ref(), system_time)?;
+
+        // calculate the reward from the streak, scaled by the treasury reserve health
+        let streak = self.state.get_next_streak(daily_reward.as_ref(), system_time);
+        let reward = self.state.get_daily_reward_amount(streak);
+        let treasury_health = self.state.get_block_treasury_health(system_time).await;
+        let amount = self.state.scale_payout(reward, &treasury_health);
+
+        // check contract balance
+        let contract_balance = self.state.app_data.get().balance;
+        if contract_balance.lt(&amount) {
+            return Err(String::from("Insufficient contract balance"));
+        }
+
+        // debit contract balance to pay for the reward
+        self.state.debit(amount).await;
+        self.state.record_treasury_outflow(amount);
+        let daily_reward = self.state.claim_daily_reward(owner, streak, amount, system_time).await;
+        Ok((daily_reward, amount))
+    }
+
+    async fn claim_quest_reward_on_root(&mut self, owner: Owner, quest_index: u64) -> Result<(QuestProgress, Amount), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        // check the quest is completed today, and not claimed yet
+        let system_time = self.runtime.system_time();
+        let (quest, mut quest_progress) = self.state.check_quest_claimable(owner, quest_index, system_time).await?;
+
+        // calculate the reward, scaled by the treasury reserve health
+        let treasury_health = self.state.get_block_treasury_health(system_time).await;
+        let amount = self.state.scale_payout(quest.reward, &treasury_health);
+
+        // check contract balance
+        let contract_balance = self.state.app_data.get().balance;
+        if contract_balance.lt(&amount) {
+            return Err(String::from("Insufficient contract balance"));
+        }
+
+        // debit contract balance to pay for the reward
+        self.state.debit(amount).await;
+        self.state.record_treasury_outflow(amount);
+        quest_progress.is_claimed = true;
+        self.state.save_quest_progress(owner, quest_progress.clone()).await;
+        Ok((quest_progress, amount))
+    }
+
+    async fn open_loan_on_root(&mut self, owner: Owner, cow_names: Vec<String>, amount: Amount) -> Result<Loan, String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+        for cow_name in cow_names.iter() {
+            self.seize_defaulted_loan_on_root(cow_name).await;
+        }
+
+        // check collateral & loan size
+        let system_time = self.runtime.system_time();
+        let cows = self.state.check_loan_collateral(owner, &cow_names, system_time).await?;
+        let max_loan = self.state.get_max_loan(cows, system_time).await;
+        if amount.eq(&Amount::ZERO) || amount.gt(&max_loan) {
+            return Err(format!("Loan amount must be more than 0, and at most {}", max_loan));
+        }
+
+        // check contract balance
+        let contract_balance = self.state.app_data.get().balance;
+        if contract_balance.lt(&amount) {
+            return Err(String::from("Insufficient contract balance"));
+        }
+
+        // debit contract balance to lend the tokens
+        self.state.debit(amount).await;
+        self.state.record_treasury_outflow(amount);
+        Ok(self.state.open_loan(owner, cow_names, amount, system_time).await)
+    }
+
+    async fn repay_loan_on_root(&mut self, owner: Owner, loan_id: u64, payment: Amount) -> Result<Amount, String> {
+        let loan = self.state
+            .get_loan(loan_id)
+            .await
+            .ok_or_else(|| format!("Loan {} is not exist", loan_id))?;
+        if loan.owner.ne(&owner) {
+            return Err(format!("Loan {} is not yours to repay", loan_id));
+        }
+
+        // defaulted loan can't be repaid, its collateral is seized instead
+        let loan_report = self.state.get_loan_report(loan.clone(), self.runtime.system_time()).await;
+        if loan_report.is_defaulted {
+            self.seize_loan_on_root(loan).await;
+            return Err(format!("Loan {} has defaulted, collateral is seized", loan_id));
+        }
+        if payment.lt(&loan_report.amount_due) {
+            return Err(format!("Loan {} amount due is {}", loan_id, loan_report.amount_due));
+        }
+
+        // credit balance to receive the principal & interest
+        self.state.credit(loan_report.amount_due).await;
+        self.state.record_treasury_inflow(loan_report.amount_due);
+        self.state.close_loan(&loan);
+        Ok(payment.saturating_sub(loan_report.amount_due))
+    }
+
+    async fn seize_loan_on_root(&mut self, loan: Loan) {
+        // remove the pledged cows from the game
+        for cow_name in loan.cow_names.iter() {
+            self.settle_cow_insurance(cow_name).await;
+            let is_exist = self.state.cow_db.contains_key(cow_name).await.unwrap_or(false);
+            if is_exist {
+                self.state.remove_cow(cow_name.clone()).await;
+                self.state.sub_player_herd_size(loan.owner).await;
+            }
+            self.close_keeper_budget_on_root(cow_name).await;
+        }
+        self.state.close_loan(&loan);
+
+        // notify to channel subscriber that the collateral is seized
+        self.broadcast(Message::LoanSeized { loan });
+    }
+
+    async fn close_cow_loan_on_root(&mut self, cow_name: &String, reason: String) {
+        let Some(loan) = self.state.get_cow_loan(cow_name).await else {
+            return;
+        };
+        self.state.close_loan(&loan);
+        if let Some(player) = self.state.get_player(loan.owner).await {
+            self.runtime
+                .prepare_message(Message::LoanClosed { loan, reason })
+                .send_to(player.chain_id);
+        }
+    }
+
+    async fn close_cow_policy_on_root(&mut self, cow_name: &String, reason: String) {
+        let Some(policy) = self.state.get_cow_policy(cow_name).await else {
+            return;
+        };
+        self.state.close_policy(&policy);
+        if let Some(player) = self.state.get_player(policy.owner).await {
+            self.runtime
+                .prepare_message(Message::InsuranceClosed { policy, reason })
+                .send_to(player.chain_id);
+        }
+    }
+
+    async fn seize_defaulted_loan_on_root(&mut self, cow_name: &String) {
+        // defaulted collateral is seized on the next root touch of the cow, without waiting for an admin
+        if let Some(loan) = self.state.get_cow_loan(cow_name).await {
+            let loan_report = self.state.get_loan_report(loan.clone(), self.runtime.system_time()).await;
+            if loan_report.is_defaulted {
+                self.seize_loan_on_root(loan).await;
+            }
+        }
+    }
+
+    async fn buy_feed_on_root(
+        &mut self,
+        owner: Owner,
+        feed_type: FeedType,
+        quantity: u64,
+        payment: Amount,
+    ) -> Result<(Amount, FeedInventory), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        // check the payment covers the feed cost
+        if quantity == 0 {
+            return Err(String::from("quantity must be more than 0"));
+        }
+        let feed_cost = self.state.get_feed_cost(feed_type, quantity);
+        if payment.lt(&feed_cost) {
+            return Err(format!("{} {:?} cost {}", quantity, feed_type, feed_cost));
+        }
+
+        // credit balance to receive the feed cost
+        self.state.credit(feed_cost).await;
+        self.state.record_treasury_inflow(feed_cost);
+        let feed_inventory = self.state.add_feed(owner, feed_type, quantity).await;
+        Ok((feed_cost, feed_inventory))
+    }
+
+    async fn rename_cow_on_root(
+        &mut self,
+        owner: Owner,
+        cow_name: String,
+        new_name: String,
+        payment: Amount,
+    ) -> Result<Amount, String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+        self.seize_defaulted_loan_on_root(&cow_name).await;
+
+        // check the cow is alive & yours, and the new name is available
+        let cow = self.state.cow_db.get(&cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        if cow.owner.ne(&owner) {
+            return Err(format!("{:?} is not yours to rename", cow_name));
+        }
+        let system_time = self.runtime.system_time();
+        if self.state.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
+            return Err(format!("you can't rename {:?}", cow_name));
+        }
+        self.state.check_cow_name_available(&new_name).await?;
+
+        // check the payment covers the renaming fee
+        let rename_fee = Amount::from_tokens(RENAME_FEE);
+        if payment.lt(&rename_fee) {
+            return Err(format!("renaming fee is {}", rename_fee));
+        }
+
+        // credit balance to receive the renaming fee
+        self.state.credit(rename_fee).await;
+        self.state.record_treasury_inflow(rename_fee);
+        self.state.rename_cow(cow_name, new_name).await;
+        Ok(rename_fee)
+    }
+
+    async fn create_coop_on_root(
+        &mut self,
+        owner: Owner,
+        name: String,
+        members: Vec<Owner>,
+        feed_rule: CoopRule,
+        trade_rule: CoopRule,
+    ) -> Result<u64, String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        // payouts are sent to the member chain, so every member must be a known player
+        for member in members.iter() {
+            if self.state.get_player(*member).await.is_none() {
+                return Err(format!("{:?} has no player chain yet", member));
+            }
+        }
+
+        let system_time = self.runtime.system_time();
+        let cooperative = self.state.create_cooperative(owner, name, members, feed_rule, trade_rule, system_time)?;
+        Ok(cooperative.id)
+    }
+
+    async fn deposit_to_coop_on_root(&mut self, owner: Owner, coop_id: u64, amount: Amount) -> Result<(), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        // deposit stays in the cooperative treasury, Root chain balance is untouched
+        let mut cooperative = self.state.check_coop_member(coop_id, owner).await?;
+        cooperative.treasury.saturating_add_assign(amount);
+        for member in cooperative.members.iter_mut() {
+            if member.owner == owner {
+                member.deposited.saturating_add_assign(amount);
+            }
+        }
+        self.state.save_cooperative(cooperative);
+        Ok(())
+    }
+
+    async fn coop_buy_cow_on_root(
+        &mut self,
+        owner: Owner,
+        coop_id: u64,
+        cow_buy_params: CowBuyParams,
+    ) -> Result<(CowData, Amount), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        let mut cooperative = self.state.check_coop_member(coop_id, owner).await?;
+        self.state.check_coop_rule(&cooperative, owner, cooperative.trade_rule)?;
+
+        // make sure the cooperative pays the current price
+        let system_time = self.runtime.system_time();
+        let cow_price = self.state.get_event_cow_price(cow_buy_params.breed, system_time);
+        if cow_buy_params.price.ne(&cow_price) {
+            return Err(format!("{:?} price has changed to {}", cow_buy_params.breed, cow_price));
+        }
+
+        // you can't buy if cow exist and alive
+        let is_cow_alive_and_exist = self.state.is_cow_alive_and_exist(cow_buy_params.name.clone(), system_time).await;
+        if is_cow_alive_and_exist {
+            return Err(format!("{:?} is not available", cow_buy_params.name));
+        }
+        self.state.check_breed_supply(cow_buy_params.breed, system_time).await?;
+        self.state.check_coop_herd_capacity(coop_id, system_time).await?;
+
+        // pay from the cooperative treasury, the cow belongs to the cooperative herd
+        self.state.spend_coop_treasury(&mut cooperative, cow_price)?;
+        let herd_owner = cooperative.herd_owner;
+        self.state.save_cooperative(cooperative);
+        let cow_data = self.mint_cow_on_root(herd_owner, cow_buy_params).await;
+
+        // count quest progress for the member who bought the cow
+        self.state.record_quest_action(owner, QuestAction::BuyCow(cow_data.breed), system_time).await;
+        Ok((cow_data, cow_price))
+    }
+
+    async fn coop_feed_cow_on_root(
+        &mut self,
+        owner: Owner,
+        coop_id: u64,
+        cow_name: String,
+        feed_type: FeedType,
+    ) -> Result<(CowData, Amount), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        let mut cooperative = self.state.check_coop_member(coop_id, owner).await?;
+        self.state.check_coop_rule(&cooperative, owner, cooperative.feed_rule)?;
+        let system_time = self.runtime.system_time();
+        let cow = self.state.get_coop_cow(&cooperative, &cow_name, system_time).await?;
+        let cow = self.get_fed_cow(cow, feed_type).await?;
+
+        // the feed is bought from the shop with the cooperative treasury, and used right away
+        let feed_price = self.state.get_feed_price(feed_type);
+        self.state.spend_coop_treasury(&mut cooperative, feed_price)?;
+        self.state.save_cooperative(cooperative);
+        self.state.credit(feed_price).await;
+        self.state.record_treasury_inflow(feed_price);
+
+        // save data to db
+        self.save_fed_cow_on_root(cow.clone(), owner).await;
+        Ok((cow, feed_price))
+    }
+
+    async fn coop_sell_cow_on_root(
+        &mut self,
+        owner: Owner,
+        coop_id: u64,
+        cow_name: String,
+    ) -> Result<(Owner, Amount), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        let cooperative = self.state.check_coop_member(coop_id, owner).await?;
+        self.state.check_coop_rule(&cooperative, owner, cooperative.trade_rule)?;
+        let system_time = self.runtime.system_time();
+        let cow = self.state.get_coop_cow(&cooperative, &cow_name, system_time).await?;
+        if self.state.is_cow_underage(cow.born_time, system_time).await {
+            return Err(format!("{:?} is too young to be sold", cow_name));
+        }
+
+        // sale proceeds are split among members by their deposit share
+        let cow_selling_price = self.sell_cow_on_root(cow, owner).await?;
+        self.send_coop_payouts(&cooperative, CoopAction::SellCow, cow_name, cow_selling_price).await;
+        Ok((cooperative.herd_owner, cow_selling_price))
+    }
+
+    async fn dissolve_coop_on_root(&mut self, owner: Owner, coop_id: u64) -> Result<Amount, String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        // only the founder can dissolve, once every cow is sold or dead
+        let cooperative = self.state.check_coop_member(coop_id, owner).await?;
+        self.state.check_coop_rule(&cooperative, owner, CoopRule::FounderOnly)?;
+        let system_time = self.runtime.system_time();
+        let herd_size = self.state.get_coop_herd(coop_id, system_time).await.len();
+        if herd_size > 0 {
+            return Err(format!("cooperative {} still has {} living cows", coop_id, herd_size));
+        }
+
+        // the rest of the treasury goes back to members by their deposit share
+        let treasury = cooperative.treasury;
+        self.send_coop_payouts(&cooperative, CoopAction::Dissolve, String::from(""), treasury).await;
+        self.state.remove_cooperative(coop_id);
+        Ok(treasury)
+    }
+
+    async fn send_coop_payouts(&mut self, cooperative: &Cooperative, action: CoopAction, cow_name: String, amount: Amount) {
+        for (member, payout) in self.state.get_coop_payouts(cooperative, amount).into_iter() {
+            if let Some(player) = self.state.get_player(member).await {
+                let message = Message::CoopPayout { coop_id: cooperative.id, action, cow_name: cow_name.clone(), amount: payout };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(player.chain_id);
+            }
+        }
     }
 
-    async fn random_cow_gender(&mut self) -> CowGender {
-        // produce seed array using system time
-        let timestamp = self.runtime.system_time().to_string();
-        let concatenated_timestamp = format!("{}{}{}", timestamp, timestamp, timestamp);
-        let timestamp_str = truncate(concatenated_timestamp.as_str(), 32);
-        let mut seed_array = [0u8; 32];
-        seed_array = <[u8; 32]>::try_from(timestamp_str.as_bytes()).unwrap();
+    async fn fund_keeper_budget_on_root(
+        &mut self,
+        owner: Owner,
+        cow_name: String,
+        feed_type: FeedType,
+        amount: Amount,
+    ) -> Result<KeeperBudget, String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+        self.seize_defaulted_loan_on_root(&cow_name).await;
 
-        // get random value using provided seed
-        let mut buff = &mut [0];
-        custom_getrandom(buff, seed_array).unwrap_or_else(|_| {
-            panic!("failed random fill");
+        let system_time = self.runtime.system_time();
+        self.state.check_keeper_fundable(owner, &cow_name, system_time).await?;
+
+        // budget stays apart from the Root chain treasury, the latest funding picks the feed type
+        let mut keeper_budget = self.state.get_keeper_budget(&cow_name).await.unwrap_or(KeeperBudget {
+            cow_name,
+            owner,
+            feed_type,
+            balance: Amount::ZERO,
+            feed_count: 0,
+            last_keeper: None,
+            last_fed_at: None,
         });
-        let val = buff.first().unwrap_or_else(|| {
-            panic!("failed to get random value");
+        keeper_budget.feed_type = feed_type;
+        keeper_budget.balance.saturating_add_assign(amount);
+        self.state.save_keeper_budget(keeper_budget.clone());
+        Ok(keeper_budget)
+    }
+
+    async fn keeper_feed_on_root(
+        &mut self,
+        keeper: Owner,
+        cow_name: String,
+    ) -> Result<(CowData, KeeperBudget, Amount), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+        self.seize_defaulted_loan_on_root(&cow_name).await;
+
+        // timing is checked against the Root chain clock
+        let system_time = self.runtime.system_time();
+        let (mut keeper_budget, cow) = self.state.check_keeper_feedable(&cow_name, system_time).await?;
+        let cow = self.get_fed_cow(cow, keeper_budget.feed_type).await?;
+
+        // the budget buys the feed from the shop, and pays the keeper fee
+        let feeding_cost = self.state.get_keeper_feeding_cost(&keeper_budget);
+        let feed_price = self.state.get_feed_price(keeper_budget.feed_type);
+        keeper_budget.balance = keeper_budget.balance.saturating_sub(feeding_cost);
+        keeper_budget.feed_count += 1;
+        keeper_budget.last_keeper = Some(keeper);
+        keeper_budget.last_fed_at = Some(system_time);
+        self.state.save_keeper_budget(keeper_budget.clone());
+        self.state.credit(feed_price).await;
+        self.state.record_treasury_inflow(feed_price);
+
+        // save data to db, the feeding counts for the owner
+        self.save_fed_cow_on_root(cow.clone(), keeper_budget.owner).await;
+        Ok((cow, keeper_budget, feeding_cost))
+    }
+
+    async fn close_keeper_budget_on_root(&mut self, cow_name: &String) {
+        // the rest of the budget goes back to the budget owner, it is never left behind for the next owner
+        if let Some(keeper_budget) = self.state.get_keeper_budget(cow_name).await {
+            self.state.remove_keeper_budget(cow_name);
+            if let Some(player) = self.state.get_player(keeper_budget.owner).await {
+                let message = Message::KeeperBudgetClosed { cow_name: cow_name.clone(), refund: keeper_budget.balance };
+                self.runtime
+                    .prepare_message(message)
+                    .send_to(player.chain_id);
+            }
+        }
+    }
+
+    async fn send_to_owner_and_caretaker(
+        &mut self,
+        message: impl Fn() -> Message,
+        owner_chain_id: ChainId,
+        caretaker: Owner,
+    ) {
+        self.runtime
+            .prepare_message(message())
+            .send_to(owner_chain_id);
+        if let Some(player) = self.state.get_player(caretaker).await {
+            if player.chain_id != owner_chain_id {
+                self.runtime
+                    .prepare_message(message())
+                    .send_to(player.chain_id);
+            }
+        }
+    }
+
+    fn push_coop_failure(&mut self, coop_id: u64, action: CoopAction, cow_name: String, amount: Amount, reason: String) {
+        self.state.coop_notification.push_back(CoopNotif {
+            coop_id,
+            action,
+            cow_name,
+            amount,
+            is_success: false,
+            failure_reason: reason,
         });
+    }
 
-        // check if random value is Even or Odd
-        if val % 2 == 0 {
-            return CowGender::Female;
+    async fn treat_cow_on_root(
+        &mut self,
+        owner: Owner,
+        cow_name: String,
+        payment: Amount,
+    ) -> Result<(CowData, Amount), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+        self.seize_defaulted_loan_on_root(&cow_name).await;
+
+        // check the cow can be treated, and the payment covers the treatment
+        let system_time = self.runtime.system_time();
+        let mut cow = self.state.check_cow_treatable(owner, &cow_name, system_time).await?;
+        let treatment_price = self.state.get_treatment_price();
+        if payment.lt(&treatment_price) {
+            return Err(format!("{:?} treatment cost {}", cow_name, treatment_price));
+        }
+
+        // credit balance to receive the treatment price
+        self.state.credit(treatment_price).await;
+        self.state.record_treasury_inflow(treatment_price);
+        cow.health = CowHealth::Healthy;
+        self.state.create_new_or_update_cow_data(cow_name, cow.clone()).await;
+        Ok((cow, treatment_price))
+    }
+
+    async fn upgrade_farm_on_root(&mut self, owner: Owner, level: u64, payment: Amount) -> Result<Amount, String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+
+        // check the farm can be upgraded, and the payment covers the cost
+        let upgrade_cost = self.state.check_farm_upgradable(owner, level).await?;
+        if payment.lt(&upgrade_cost) {
+            return Err(format!("farm upgrade to level {} cost {}", level, upgrade_cost));
+        }
+
+        // credit balance to receive the upgrade cost
+        self.state.credit(upgrade_cost).await;
+        self.state.record_treasury_inflow(upgrade_cost);
+        self.state.save_farm_level(owner, level);
+        Ok(upgrade_cost)
+    }
+
+    async fn issue_policy_on_root(
+        &mut self,
+        owner: Owner,
+        cow_name: String,
+        payment: Amount,
+    ) -> Result<(InsurancePolicy, Amount), String> {
+        // reject while the game is paused
+        self.state.check_game_not_paused()?;
+        self.seize_defaulted_loan_on_root(&cow_name).await;
+
+        // check the cow can be insured, and the payment covers the premium
+        let system_time = self.runtime.system_time();
+        let cow = self.state.check_cow_insurable(owner, &cow_name, system_time).await?;
+        let insurance_quote = self.state.get_insurance_quote(cow, system_time).await;
+        if payment.lt(&insurance_quote.premium) {
+            return Err(format!("{:?} insurance premium is {}", cow_name, insurance_quote.premium));
+        }
+
+        // credit balance to receive the premium
+        self.state.credit(insurance_quote.premium).await;
+        self.state.record_treasury_inflow(insurance_quote.premium);
+        let policy = self.state.open_policy(
+            owner, cow_name, insurance_quote.premium, insurance_quote.coverage, system_time,
+        ).await;
+        Ok((policy, payment.saturating_sub(insurance_quote.premium)))
+    }
+
+    async fn claim_insurance_on_root(&mut self, owner: Owner, cow_name: String) -> Result<(InsurancePolicy, Amount), String> {
+        let policy = self.state
+            .get_cow_policy(&cow_name)
+            .await
+            .ok_or_else(|| format!("{:?} is not insured", cow_name))?;
+        if policy.owner.ne(&owner) {
+            return Err(format!("{:?} insurance is not yours to claim", cow_name));
+        }
+        let payout = self.pay_insurance_on_root(policy.clone()).await?;
+        Ok((policy, payout))
+    }
+
+    async fn pay_insurance_on_root(&mut self, policy: InsurancePolicy) -> Result<Amount, String> {
+        // calculate the payout, scaled by the treasury reserve health
+        let system_time = self.runtime.system_time();
+        self.state.check_insurance_claimable(&policy, system_time).await?;
+        let treasury_health = self.state.get_block_treasury_health(system_time).await;
+        let payout = self.state.scale_payout(policy.coverage, &treasury_health);
+
+        // check contract balance
+        let contract_balance = self.state.app_data.get().balance;
+        if contract_balance.lt(&payout) {
+            return Err(String::from("Insufficient contract balance"));
+        }
+
+        // debit contract balance to pay the coverage
+        self.state.debit(payout).await;
+        self.state.record_treasury_outflow(payout);
+        self.state.close_policy(&policy);
+        Ok(payout)
+    }
+
+    async fn award_achievement(&mut self, owner: Owner, achievement: Achievement) {
+        if self.state.has_achievement(owner, achievement).await {
+            return;
+        }
+
+        // one-time bonus, scaled by the treasury reserve health
+        // achievement is still awarded without bonus when the contract balance is short
+        let system_time = self.runtime.system_time();
+        let treasury_health = self.state.get_block_treasury_health(system_time).await;
+        let mut bonus = self.state.scale_payout(self.state.get_achievement_bonus(achievement), &treasury_health);
+        let contract_balance = self.state.app_data.get().balance;
+        if contract_balance.lt(&bonus) {
+            bonus = Amount::ZERO;
+        }
+        if bonus.gt(&Amount::ZERO) {
+            self.state.debit(bonus).await;
+            self.state.record_treasury_outflow(bonus);
+        }
+
+        // notify the player
+        let player_achievement = PlayerAchievement { owner, achievement, bonus, awarded_at: system_time };
+        self.state.add_achievement(player_achievement.clone()).await;
+        if let Some(player) = self.state.get_player(owner).await {
+            let message = Message::AchievementAwarded { player_achievement };
+            self.runtime
+                .prepare_message(message)
+                .send_to(player.chain_id);
+        }
+    }
+
+    async fn settle_cow_insurance(&mut self, cow_name: &String) {
+        // pay the policy of a dead cow before it leaves the game, or close it
+        let Some(policy) = self.state.get_cow_policy(cow_name).await else {
+            return;
+        };
+        let message = match self.pay_insurance_on_root(policy.clone()).await {
+            Ok(payout) => Message::InsurancePaid { policy: policy.clone(), payout },
+            Err(reason) => {
+                self.state.close_policy(&policy);
+                Message::InsuranceClosed { policy: policy.clone(), reason }
+            }
+        };
+        if let Some(player) = self.state.get_player(policy.owner).await {
+            self.runtime
+                .prepare_message(message)
+                .send_to(player.chain_id);
+        }
+    }
+
+    async fn save_fed_cow(&mut self, cow_data: CowData) {
+        // check if CowData belong to us
+        let cow_name = cow_data.name.clone();
+        let is_my_cow = self.state
+            .is_cow_exist_in_db_and_ownership(cow_name.clone()).await;
+        if is_my_cow {
+            // check if the cow is actually belong to us
+            let owner_id = self.state.app_data.get().owner;
+            if owner_id.ne(&cow_data.owner) {
+                self.state.cow_ownership.remove(&cow_data.name).unwrap_or_else(|_| {
+                    panic!("unable to remove Cow from ownership");
+                });
+            }
+            // do nothing if all check passed
+            return;
+        }
+
+        // else, save data to db
+        self.state.create_new_or_update_cow_data(cow_name, cow_data).await;
+    }
+
+    async fn complete_cow_sale(&mut self, cow_name: String, cow_owner: Owner, payment: Amount) {
+        // update Cow Ownership & Sell Notification only on Seller's local state
+        let owner = self.state.app_data.get().owner;
+        if owner.eq(&cow_owner) {
+            // remove Cow from ownership
+            self.state.cow_ownership.remove(&cow_name).unwrap_or_else(|_| {
+                panic!("unable to remove Cow from ownership");
+            });
+
+            // credit balance to receive payment for the cow
+            self.state.credit(payment).await;
+
+            // sold cow is no longer insured
+            if let Some(policy) = self.state.get_cow_policy(&cow_name).await {
+                self.state.close_policy(&policy);
+            }
+
+            // push sell notification
+            self.state.sell_notification.push_back(SellNotif {
+                cow_name: cow_name.clone(),
+                is_success: true,
+                failure_reason: String::from(""),
+            });
+        } else {
+            // check Cow in subscriber's ownership, remove if it does exist.
+            let cow_in_ownership = self.state.cow_ownership.contains_key(&cow_name)
+                .await.unwrap_or(false);
+            if cow_in_ownership {
+                self.state.cow_ownership.remove(&cow_name).unwrap_or_else(|_| {
+                    panic!("unable to remove Cow from ownership");
+                });
+            }
+        }
+
+        // remove Cow from DB
+        self.state.cow_db.remove(&cow_name).unwrap_or_else(|_| {
+            panic!("unable to remove Cow from DB");
+        });
+    }
+
+    async fn reprice_breed(&mut self, breed: CowBreed) -> bool {
+        let system_time = self.runtime.system_time();
+        self.state.reprice_breed(breed, system_time).await
+    }
+
+    fn broadcast(&mut self, message: Message) {
+        self.runtime
+            .prepare_message(message)
+            .send_to(Destination::from(ChannelName::from(MICRO_COW_CHANNEL.to_vec())));
+    }
+
+    fn send_game_snapshot(&mut self, chain_id: ChainId) {
+        // current game settings that were broadcast before the chain subscribed
+        let messages = vec![
+            Message::GameConfigUpdated { game_config: self.state.get_game_config() },
+            Message::ReserveRatioUpdated { min_reserve_ratio: self.state.get_min_reserve_ratio() },
+            Message::BreedSuppliesUpdated { breed_supplies: self.state.get_breed_supplies() },
+            Message::GamePaused { pause_state: self.state.pause_state.get().clone() },
+            Message::SeasonalEventsUpdated { seasonal_events: self.state.get_seasonal_events() },
+            Message::AdminsUpdated { admins: self.state.get_admins() },
+            Message::QuestScheduleUpdated { quest_schedule: self.state.get_quest_schedule() },
+        ];
+        for message in messages.into_iter() {
+            self.runtime
+                .prepare_message(message)
+                .send_to(chain_id);
         }
-        CowGender::Male
     }
 
     fn subscribe_to_micro_cow_channel(&mut self) {
         let root_chain_id = self.runtime.application_parameters().root_chain_id;
         self.runtime
             .prepare_message(Message::Subscribe)
+            .with_authentication()
             .with_tracking()
             .send_to(root_chain_id);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use futures::executor::block_on;
+    use linera_sdk::{Contract, ContractRuntime};
+    use linera_sdk::base::{Amount, BlockHeight, ChainId, MessageId, Owner, Timestamp};
+    use micro_cow_linera::{Achievement, CaretakerGrant, CowBreed, CowBuyParams, CowData, CowGender, CowOperation, DailyReward, FeedType, FeedingStats, FeedingStatus, Message, MicroCowParameters, PauseState};
+    use micro_cow_linera::constants::*;
+    use super::MicroCowContract;
+
+    const START_TIME: u64 = 10 * UNIX_MICROS_IN_24_HOURS;
+
+    fn test_owner(byte: &str) -> Owner {
+        Owner::from_str(&byte.repeat(32)).unwrap()
+    }
+
+    fn test_runtime(chain_id: ChainId, signer: Owner, system_time: u64) -> ContractRuntime<MicroCowContract> {
+        ContractRuntime::new()
+            .with_application_parameters(MicroCowParameters { root_chain_id: ChainId::root(0) })
+            .with_chain_id(chain_id)
+            .with_authenticated_signer(signer)
+            .with_system_time(Timestamp::from(system_time))
+    }
+
+    fn player_contract(owner: Owner, system_time: u64) -> MicroCowContract {
+        let mut contract = block_on(MicroCowContract::load(test_runtime(ChainId::root(1), owner, system_time)));
+        block_on(contract.state.initialize(owner, ChainId::root(1), Amount::from_tokens(USER_INITIAL_TOKEN), false));
+        contract
+    }
+
+    fn root_contract(system_time: u64) -> MicroCowContract {
+        let mut contract = block_on(MicroCowContract::load(test_runtime(ChainId::root(0), test_owner("ad"), system_time)));
+        block_on(contract.instantiate(Amount::from_tokens(100_000)));
+        contract
+    }
+
+    fn receive_message(contract: &mut MicroCowContract, sender: Owner, message: Message) {
+        contract.runtime
+            .set_authenticated_signer(sender)
+            .set_message_is_bouncing(false)
+            .set_message_id(MessageId { chain_id: ChainId::root(1), height: BlockHeight(0), index: 0 });
+        block_on(contract.execute_message(message));
+    }
+
+    fn buy_cow_message(contract: &MicroCowContract, owner: Owner, name: &str, breed: CowBreed) -> Message {
+        let price = contract.state.get_cow_price(breed);
+        let cow_buy_params = CowBuyParams::new(String::from("cow-id"), String::from(name), breed, CowGender::Female, price);
+        Message::BuyCow { owner, cow_buy_params }
+    }
+
+    fn test_cow(name: &str, owner: Owner, last_fed_time: u64) -> CowData {
+        CowData::new(
+            String::from("cow-id"),
+            String::from(name),
+            CowBreed::Jersey,
+            CowGender::Female,
+            Timestamp::from(0),
+            Timestamp::from(last_fed_time),
+            FeedingStats::new(),
+            owner,
+        )
+    }
+
+    fn give_cow(contract: &mut MicroCowContract, name: &str, last_fed_time: u64) {
+        let owner = contract.state.app_data.get().owner;
+        block_on(contract.state.create_new_or_update_cow_data(String::from(name), test_cow(name, owner, last_fed_time)));
+        block_on(contract.state.update_cow_ownership(String::from(name)));
+    }
+
+    fn pause_game(contract: &mut MicroCowContract) {
+        let reason = String::from("exploit");
+        block_on(contract.execute_operation(CowOperation::AdminPauseGame { owner: test_owner("ad"), reason }));
+    }
+
+    fn give_root_cow(contract: &mut MicroCowContract, name: &str, owner: Owner, last_fed_time: u64) {
+        block_on(contract.state.create_new_or_update_cow_data(String::from(name), test_cow(name, owner, last_fed_time)));
+    }
+
+    fn get_cow(contract: &MicroCowContract, name: &str) -> CowData {
+        block_on(contract.state.cow_db.get(&String::from(name))).unwrap().unwrap()
+    }
+
+    #[test]
+    fn feeding_status_follows_the_feeding_windows() {
+        let contract = player_contract(test_owner("01"), START_TIME);
+        let status_after = |micros: u64| contract.state.get_feeding_status(Timestamp::from(START_TIME), Timestamp::from(START_TIME + micros));
+        assert_eq!(status_after(0), FeedingStatus::Full);
+        assert_eq!(status_after(WELL_FED), FeedingStatus::Full);
+        assert_eq!(status_after(WELL_FED + 1), FeedingStatus::OnTime);
+        assert_eq!(status_after(ON_TIME_FEED + 1), FeedingStatus::Late);
+        assert_eq!(status_after(LATE_FEED + 1), FeedingStatus::Forgot);
+        assert_eq!(status_after(UNIX_MICROS_IN_24_HOURS), FeedingStatus::Forgot);
+        assert_eq!(status_after(UNIX_MICROS_IN_24_HOURS + 1), FeedingStatus::Dead);
+    }
+
+    #[test]
+    fn feed_cow_counts_the_window_the_schedule_shows() {
+        let owner = test_owner("01");
+        let mut contract = player_contract(owner, START_TIME + ON_TIME_FEED + 1);
+        give_cow(&mut contract, "Bessie", START_TIME);
+
+        let system_time = Timestamp::from(START_TIME + ON_TIME_FEED + 1);
+        let schedule = contract.state.get_feeding_schedule(&get_cow(&contract, "Bessie"), system_time);
+        assert_eq!(schedule.status, FeedingStatus::Late);
+        assert_eq!(schedule.late_at, Timestamp::from(START_TIME + ON_TIME_FEED));
+        assert_eq!(schedule.death_at, Timestamp::from(START_TIME + UNIX_MICROS_IN_24_HOURS));
+
+        block_on(contract.state.add_feed(owner, FeedType::BasicHay, 1));
+        block_on(contract.execute_operation(CowOperation::FeedCow { owner, cow_name: String::from("Bessie"), feed_type: FeedType::BasicHay }));
+
+        let cow = get_cow(&contract, "Bessie");
+        assert_eq!(cow.feeding_stats.late, 1);
+        assert_eq!(cow.feeding_stats.on_time, 0);
+        assert_eq!(cow.last_fed_time, system_time);
+        assert_eq!(contract.state.get_feeding_schedule(&cow, system_time).status, FeedingStatus::Full);
+    }
+
+    #[test]
+    #[should_panic(expected = "still full")]
+    fn full_cow_cannot_be_fed() {
+        let owner = test_owner("01");
+        let mut contract = player_contract(owner, START_TIME + WELL_FED);
+        give_cow(&mut contract, "Bessie", START_TIME);
+        block_on(contract.execute_operation(CowOperation::FeedCow { owner, cow_name: String::from("Bessie"), feed_type: FeedType::BasicHay }));
+    }
+
+    #[test]
+    fn sell_quote_itemizes_the_appraisal_price() {
+        let owner = test_owner("01");
+        let contract = player_contract(owner, START_TIME);
+        let mut cow = test_cow("Bessie", owner, START_TIME);
+        cow.feeding_stats = FeedingStats { on_time: 4, late: 2, forgot: 1 };
+        let base_price = Amount::from_tokens(JERSEY_PRICE);
+
+        let quote = contract.state.get_cow_sell_quote(&cow, base_price, Timestamp::from(START_TIME));
+        assert_eq!(quote.base_price, base_price);
+        assert_eq!(quote.on_time_reward, Amount::from_tokens(20));
+        assert_eq!(quote.late_reward, Amount::from_tokens(5));
+        assert_eq!(quote.forgot_penalty, Amount::from_tokens(10));
+        assert!(!quote.is_penalty_capped);
+        assert_eq!(quote.payout, Amount::from_tokens(1015));
+        assert_eq!(block_on(contract.state.get_cow_appraisal_price(cow, base_price, Timestamp::from(START_TIME))), quote.payout);
+    }
+
+    #[test]
+    fn sell_quote_caps_the_penalty_at_the_base_price() {
+        let owner = test_owner("01");
+        let contract = player_contract(owner, START_TIME);
+        let mut cow = test_cow("Bessie", owner, START_TIME);
+        cow.feeding_stats = FeedingStats { on_time: 0, late: 0, forgot: 150 };
+
+        let quote = contract.state.get_cow_sell_quote(&cow, Amount::from_tokens(JERSEY_PRICE), Timestamp::from(START_TIME));
+        assert_eq!(quote.forgot_penalty, Amount::from_tokens(1500));
+        assert!(quote.is_penalty_capped);
+        assert_eq!(quote.payout, Amount::ZERO);
+    }
+
+    #[test]
+    fn herd_stats_follow_buys_feeds_and_sells() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        let message = buy_cow_message(&contract, owner, "Bessie", CowBreed::Jersey);
+        receive_message(&mut contract, owner, message);
+
+        let report = block_on(contract.state.get_herd_stats_report(Timestamp::from(START_TIME)));
+        assert_eq!(report.total_bought, 1);
+        assert_eq!(report.total_living, 1);
+        assert_eq!(report.active_players, 1);
+        assert_eq!(report.treasury_inflow, Amount::from_tokens(JERSEY_PRICE));
+        let jersey = report.living_cows.iter().find(|population| population.breed == CowBreed::Jersey).unwrap();
+        assert_eq!((jersey.male, jersey.female), (0, 1));
+
+        // the player chain fed the cow on time
+        let fed_time = Timestamp::from(START_TIME + WELL_FED + 1);
+        contract.runtime.set_system_time(fed_time);
+        let mut cow_data = get_cow(&contract, "Bessie");
+        cow_data.feeding_stats.on_time += 1;
+        cow_data.last_fed_time = fed_time;
+        block_on(contract.state.add_feed(owner, FeedType::BasicHay, 1));
+        receive_message(&mut contract, owner, Message::FeedCow { owner, cow_data, feed_type: FeedType::BasicHay });
+
+        let report = block_on(contract.state.get_herd_stats_report(fed_time));
+        assert_eq!(report.feeding_stats.on_time, 1);
+        assert_eq!(report.average_feeding_quality, PRECISION_100_PERCENT as u64);
+
+        // the cow has grown old enough to be sold
+        let mut cow_data = get_cow(&contract, "Bessie");
+        cow_data.born_time = Timestamp::from(0);
+        block_on(contract.state.create_new_or_update_cow_data(String::from("Bessie"), cow_data));
+
+        let payout = block_on(contract.state.cow_sell_value(get_cow(&contract, "Bessie"), fed_time));
+        receive_message(&mut contract, owner, Message::SellCow { owner, cow_name: String::from("Bessie") });
+
+        let report = block_on(contract.state.get_herd_stats_report(fed_time));
+        assert_eq!(report.total_sold, 1);
+        assert_eq!(report.total_living, 0);
+        assert_eq!(report.treasury_outflow, payout);
+    }
+
+    #[test]
+    fn player_registry_tracks_subscribers_and_their_herd() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        receive_message(&mut contract, owner, Message::Subscribe);
+
+        let player = block_on(contract.state.get_player_by_chain(ChainId::root(1))).unwrap();
+        assert_eq!(player.owner, owner);
+        assert_eq!(player.join_time, Timestamp::from(START_TIME));
+        assert_eq!(player.herd_size, 0);
+
+        contract.runtime.set_system_time(Timestamp::from(START_TIME + 1));
+        let message = buy_cow_message(&contract, owner, "Bessie", CowBreed::Jersey);
+        receive_message(&mut contract, owner, message);
+
+        let player = block_on(contract.state.get_player(owner)).unwrap();
+        assert_eq!(player.chain_id, ChainId::root(1));
+        assert_eq!(player.join_time, Timestamp::from(START_TIME));
+        assert_eq!(player.last_activity, Timestamp::from(START_TIME + 1));
+        assert_eq!(player.herd_size, 1);
+    }
+
+    #[test]
+    fn registry_herd_size_counts_a_dead_cow_until_it_leaves_root() {
+        let owner = test_owner("01");
+        let buyer = test_owner("02");
+        let mut contract = root_contract(START_TIME);
+        for name in ["Bessie", "Daisy"] {
+            let message = buy_cow_message(&contract, owner, name, CowBreed::Jersey);
+            receive_message(&mut contract, owner, message);
+        }
+        let herd_size = |contract: &MicroCowContract, owner: Owner| block_on(contract.state.get_player(owner)).unwrap().herd_size;
+        assert_eq!(herd_size(&contract, owner), 2);
+
+        // both cows starve, Bessie moves to the buyer's herd once it's bought back
+        contract.runtime.set_system_time(Timestamp::from(START_TIME + UNIX_MICROS_IN_24_HOURS + 1));
+        let message = buy_cow_message(&contract, buyer, "Bessie", CowBreed::Jersey);
+        receive_message(&mut contract, buyer, message);
+        assert_eq!(herd_size(&contract, owner), 1);
+        assert_eq!(herd_size(&contract, buyer), 1);
+
+        contract.runtime.set_authenticated_signer(test_owner("ad"));
+        block_on(contract.execute_operation(CowOperation::AdminRemoveCow { owner: test_owner("ad"), cow_name: String::from("Daisy") }));
+        assert_eq!(herd_size(&contract, owner), 0);
+    }
+
+    #[test]
+    fn batch_feed_gives_each_cow_its_own_result() {
+        let owner = test_owner("01");
+        let mut contract = player_contract(owner, START_TIME + WELL_FED + 1);
+        give_cow(&mut contract, "Bessie", START_TIME);
+        give_cow(&mut contract, "Daisy", START_TIME + WELL_FED);
+
+        block_on(contract.state.add_feed(owner, FeedType::BasicHay, 2));
+
+        let cow_names = vec![String::from("Bessie"), String::from("Daisy")];
+        block_on(contract.execute_operation(CowOperation::FeedCows { owner, cow_names, feed_type: FeedType::BasicHay }));
+
+        let notifications = block_on(contract.state.feed_notification.elements()).unwrap();
+        assert_eq!(notifications.len(), 2);
+        assert!(notifications[0].is_success);
+        assert!(!notifications[1].is_success);
+        assert!(notifications[1].failure_reason.contains("still full"));
+        assert_eq!(get_cow(&contract, "Bessie").feeding_stats.on_time, 1);
+        assert_eq!(block_on(contract.state.get_feed_inventory(owner)).count(FeedType::BasicHay), 1);
+
+        let requests = contract.runtime.created_send_message_requests();
+        assert_eq!(requests.len(), 1);
+        assert!(matches!(&requests[0].message, Message::FeedCows { cows, .. } if cows.len() == 1 && cows[0].name == "Bessie"));
+    }
+
+    #[test]
+    fn batch_sell_pays_sellable_cows_and_reports_the_rest() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        give_root_cow(&mut contract, "Bessie", owner, START_TIME);
+
+        let cow_names = vec![String::from("Bessie"), String::from("Ghost")];
+        receive_message(&mut contract, owner, Message::SellCows { owner, cow_names });
+
+        assert!(!block_on(contract.state.cow_db.contains_key(&String::from("Bessie"))).unwrap());
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(requests.iter().any(|request| matches!(
+            &request.message,
+            Message::SellCowsSuccess { sales, .. } if sales.len() == 1 && sales[0].cow_name == "Bessie"
+        )));
+        assert!(requests.iter().any(|request| matches!(
+            &request.message,
+            Message::SellCowsFailure { failures } if failures.len() == 1 && failures[0].cow_name == "Ghost"
+        )));
+    }
+
+    #[test]
+    fn admin_price_change_is_broadcast() {
+        let admin = test_owner("ad");
+        let mut contract = root_contract(START_TIME);
+        let price = Amount::from_tokens(2000);
+        block_on(contract.execute_operation(CowOperation::AdminSetCowPrice { owner: admin, cow_breed: CowBreed::Jersey, price }));
+
+        assert_eq!(contract.state.get_cow_price(CowBreed::Jersey), price);
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(matches!(&requests[0].message, Message::GameConfigUpdated { game_config }
+            if game_config.cow_prices.iter().any(|breed_price| breed_price.breed == CowBreed::Jersey && breed_price.price == price)));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not an admin")]
+    fn non_admin_cannot_run_admin_operations() {
+        let player = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        contract.runtime.set_authenticated_signer(player);
+        block_on(contract.execute_operation(CowOperation::AdminTopUpTreasury { owner: player, amount: Amount::from_tokens(1) }));
+    }
+
+    #[test]
+    fn admin_rename_moves_the_cow() {
+        let admin = test_owner("ad");
+        let mut contract = root_contract(START_TIME);
+        give_root_cow(&mut contract, "Bessie", test_owner("01"), START_TIME);
+
+        let operation = CowOperation::AdminRenameCow { owner: admin, cow_name: String::from("Bessie"), new_name: String::from("Clover") };
+        block_on(contract.execute_operation(operation));
+
+        assert!(!block_on(contract.state.cow_db.contains_key(&String::from("Bessie"))).unwrap());
+        assert_eq!(get_cow(&contract, "Clover").name, "Clover");
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(matches!(&requests[0].message, Message::CowRenamed { cow_name, new_name } if cow_name == "Bessie" && new_name == "Clover"));
+    }
+
+    #[test]
+    fn admin_supply_and_reserve_changes_are_broadcast_and_snapshot() {
+        let admin = test_owner("ad");
+        let mut contract = root_contract(START_TIME);
+        block_on(contract.execute_operation(CowOperation::AdminSetMinReserveRatio { owner: admin, min_reserve_ratio: 5_000 }));
+        block_on(contract.execute_operation(CowOperation::AdminSetPopulationCap { owner: admin, cow_breed: CowBreed::Jersey, population_cap: Some(10) }));
+        block_on(contract.execute_operation(CowOperation::AdminReleaseLimitedEdition { owner: admin, cow_breed: CowBreed::Holstein, total_supply: 3 }));
+
+        let breed_supplies = contract.state.get_breed_supplies();
+        {
+            let requests = contract.runtime.created_send_message_requests();
+            assert!(matches!(&requests[0].message, Message::ReserveRatioUpdated { min_reserve_ratio: 5_000 }));
+            assert!(matches!(&requests[1].message, Message::BreedSuppliesUpdated { breed_supplies }
+                if breed_supplies.iter().any(|supply| supply.breed == CowBreed::Jersey && supply.population_cap == Some(10))));
+            assert!(matches!(&requests[2].message, Message::BreedSuppliesUpdated { breed_supplies: supplies } if *supplies == breed_supplies));
+        }
+
+        // a chain subscribing later gets the same settings
+        receive_message(&mut contract, test_owner("01"), Message::Subscribe);
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(requests.iter().skip(3).any(|request| matches!(&request.message, Message::ReserveRatioUpdated { min_reserve_ratio: 5_000 })));
+        assert!(requests.iter().skip(3).any(|request| matches!(&request.message, Message::BreedSuppliesUpdated { breed_supplies: supplies }
+            if *supplies == breed_supplies)));
+
+        // and the player chain keeps them
+        let mut player = player_contract(test_owner("01"), START_TIME);
+        receive_message(&mut player, test_owner("01"), Message::ReserveRatioUpdated { min_reserve_ratio: 5_000 });
+        receive_message(&mut player, test_owner("01"), Message::BreedSuppliesUpdated { breed_supplies: breed_supplies.clone() });
+        assert_eq!(player.state.get_min_reserve_ratio(), 5_000);
+        assert_eq!(player.state.get_breed_supplies(), breed_supplies);
+    }
+
+    #[test]
+    fn admin_remove_cow_closes_its_loan_and_policy() {
+        let admin = test_owner("ad");
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        let system_time = Timestamp::from(START_TIME);
+        receive_message(&mut contract, owner, Message::Subscribe);
+        give_root_cow(&mut contract, "Bessie", owner, START_TIME);
+        give_root_cow(&mut contract, "Daisy", owner, START_TIME);
+        let bessie = String::from("Bessie");
+        let pledged_cows = vec![bessie.clone(), String::from("Daisy")];
+        let loan = block_on(contract.state.open_loan(owner, pledged_cows, Amount::from_tokens(100), system_time));
+        block_on(contract.state.open_policy(owner, bessie.clone(), Amount::from_tokens(25), Amount::from_tokens(500), system_time));
+
+        contract.runtime.set_authenticated_signer(admin);
+        block_on(contract.execute_operation(CowOperation::AdminRemoveCow { owner: admin, cow_name: bessie.clone() }));
+
+        assert!(!block_on(contract.state.cow_db.contains_key(&bessie)).unwrap());
+        assert_eq!(block_on(contract.state.get_loan(loan.id)), None);
+        assert!(!block_on(contract.state.is_cow_pledged(&String::from("Daisy"))));
+        assert_eq!(block_on(contract.state.get_cow_policy(&bessie)), None);
+        assert_eq!(*contract.state.total_removed.get(), 1);
+        assert_eq!(block_on(contract.state.get_herd_stats_report(system_time)).total_removed, 1);
+
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(requests.iter().any(|request| matches!(&request.message, Message::LoanClosed { loan: closed_loan, .. } if closed_loan.id == loan.id)));
+        assert!(requests.iter().any(|request| matches!(&request.message, Message::InsuranceClosed { policy, .. } if policy.cow_name == "Bessie")));
+        assert!(requests.iter().any(|request| matches!(&request.message, Message::CowRemoved { cow_name } if cow_name == "Bessie")));
+    }
+
+    #[test]
+    fn paused_root_rejects_feeding_and_selling() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        give_root_cow(&mut contract, "Bessie", owner, START_TIME);
+        pause_game(&mut contract);
+
+        let mut cow_data = get_cow(&contract, "Bessie");
+        cow_data.feeding_stats.on_time += 1;
+        receive_message(&mut contract, owner, Message::FeedCow { owner, cow_data, feed_type: FeedType::BasicHay });
+        receive_message(&mut contract, owner, Message::SellCow { owner, cow_name: String::from("Bessie") });
+        let message = buy_cow_message(&contract, owner, "Daisy", CowBreed::Jersey);
+        receive_message(&mut contract, owner, message);
+
+        assert_eq!(get_cow(&contract, "Bessie").feeding_stats.on_time, 0);
+        assert!(!block_on(contract.state.cow_db.contains_key(&String::from("Daisy"))).unwrap());
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(requests.iter().any(|request| matches!(
+            &request.message,
+            Message::BuyFailure { cow_buy_params, reason, .. } if cow_buy_params.name == "Daisy" && reason.contains("paused")
+        )));
+        assert!(requests.iter().any(|request| matches!(
+            &request.message,
+            Message::FeedFailure { cows, reason, .. } if cows[0].feeding_stats.on_time == 0 && reason.contains("paused")
+        )));
+        assert!(requests.iter().any(|request| matches!(
+            &request.message,
+            Message::SellFailure { reason, .. } if reason.contains("paused")
+        )));
+    }
+
+    #[test]
+    #[should_panic(expected = "Micro Cow is paused")]
+    fn paused_player_chain_rejects_feeding() {
+        let owner = test_owner("01");
+        let mut contract = player_contract(owner, START_TIME + WELL_FED + 1);
+        give_cow(&mut contract, "Bessie", START_TIME);
+        contract.state.pause_state.set(PauseState {
+            is_paused: true,
+            reason: String::from("exploit"),
+            paused_at: Timestamp::from(START_TIME),
+        });
+        block_on(contract.execute_operation(CowOperation::FeedCow { owner, cow_name: String::from("Bessie"), feed_type: FeedType::BasicHay }));
+    }
+
+    #[test]
+    fn resume_shifts_the_hunger_clock() {
+        let admin = test_owner("ad");
+        let mut contract = root_contract(START_TIME);
+        give_root_cow(&mut contract, "Bessie", test_owner("01"), START_TIME);
+        pause_game(&mut contract);
+
+        // the game stays paused for two days
+        let resumed_at = START_TIME + 2 * UNIX_MICROS_IN_24_HOURS;
+        contract.runtime.set_system_time(Timestamp::from(resumed_at));
+        assert_eq!(contract.state.get_game_time(Timestamp::from(resumed_at)), Timestamp::from(START_TIME));
+        block_on(contract.execute_operation(CowOperation::AdminResumeGame { owner: admin }));
+
+        let cow = get_cow(&contract, "Bessie");
+        assert_eq!(cow.last_fed_time, Timestamp::from(resumed_at));
+        assert!(!contract.state.pause_state.get().is_paused);
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(requests.iter().any(|request| matches!(&request.message, Message::GameResumed { .. })));
+    }
+
+    #[test]
+    fn resume_moves_the_deadlines_that_were_running() {
+        let admin = test_owner("ad");
+        let owner = test_owner("01");
+        let caretaker = test_owner("02");
+        let mut contract = root_contract(START_TIME);
+        let paused_at = Timestamp::from(START_TIME);
+        give_root_cow(&mut contract, "Bessie", owner, START_TIME);
+        let bessie = String::from("Bessie");
+        let loan = block_on(contract.state.open_loan(owner, vec![bessie.clone()], Amount::from_tokens(100), paused_at));
+        let policy = block_on(contract.state.open_policy(owner, bessie.clone(), Amount::from_tokens(25), Amount::from_tokens(500), paused_at));
+        let last_claim_time = Timestamp::from(START_TIME - UNIX_MICROS_IN_24_HOURS);
+        contract.state.save_daily_reward(DailyReward { owner, streak: 3, last_claim_time, total_claimed: Amount::ZERO });
+        let grant = CaretakerGrant {
+            owner,
+            caretaker,
+            cow_names: vec![bessie.clone()],
+            granted_at: paused_at,
+            expires_at: paused_at.saturating_add_micros(UNIX_MICROS_IN_24_HOURS),
+            feed_count: 0,
+            last_fed_at: None,
+        };
+        block_on(contract.state.save_caretaker_grant(grant.clone(), paused_at));
+
+        // a daily reward streak that has expired before the pause stays expired
+        let expired_claim_time = Timestamp::from(START_TIME - 3 * UNIX_MICROS_IN_24_HOURS);
+        let other_owner = test_owner("03");
+        contract.state.save_daily_reward(DailyReward { owner: other_owner, streak: 5, last_claim_time: expired_claim_time, total_claimed: Amount::ZERO });
+
+        pause_game(&mut contract);
+        let paused_duration = 2 * UNIX_MICROS_IN_24_HOURS;
+        contract.runtime.set_system_time(Timestamp::from(START_TIME + paused_duration));
+        block_on(contract.execute_operation(CowOperation::AdminResumeGame { owner: admin }));
+
+        let resumed_loan = block_on(contract.state.get_loan(loan.id)).unwrap();
+        assert_eq!(resumed_loan.start_time, loan.start_time.saturating_add_micros(paused_duration));
+        assert_eq!(resumed_loan.due_time, loan.due_time.saturating_add_micros(paused_duration));
+        let resumed_policy = block_on(contract.state.get_cow_policy(&bessie)).unwrap();
+        assert_eq!(resumed_policy.start_time, policy.start_time);
+        assert_eq!(resumed_policy.end_time, policy.end_time.saturating_add_micros(paused_duration));
+        let daily_reward = block_on(contract.state.get_daily_reward(owner)).unwrap();
+        assert_eq!(daily_reward.last_claim_time, last_claim_time.saturating_add_micros(paused_duration));
+        let expired_reward = block_on(contract.state.get_daily_reward(other_owner)).unwrap();
+        assert_eq!(expired_reward.last_claim_time, expired_claim_time);
+        let resumed_grant = block_on(contract.state.get_caretaker_grants(owner)).pop().unwrap();
+        assert_eq!(resumed_grant.expires_at, grant.expires_at.saturating_add_micros(paused_duration));
+    }
+
+    #[test]
+    fn population_cap_rejects_extra_buys() {
+        let admin = test_owner("ad");
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        let operation = CowOperation::AdminSetPopulationCap { owner: admin, cow_breed: CowBreed::Jersey, population_cap: Some(1) };
+        block_on(contract.execute_operation(operation));
+
+        let message = buy_cow_message(&contract, owner, "Bessie", CowBreed::Jersey);
+        receive_message(&mut contract, owner, message);
+        let message = buy_cow_message(&contract, owner, "Daisy", CowBreed::Jersey);
+        receive_message(&mut contract, owner, message);
+
+        assert!(block_on(contract.state.cow_db.contains_key(&String::from("Bessie"))).unwrap());
+        assert!(!block_on(contract.state.cow_db.contains_key(&String::from("Daisy"))).unwrap());
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(requests.iter().any(|request| matches!(
+            &request.message,
+            Message::BuyFailure { cow_buy_params, reason, .. }
+                if cow_buy_params.name == "Daisy" && reason.contains("population has reached its cap")
+        )));
+    }
+
+    #[test]
+    fn daily_reward_has_a_cooldown_and_a_streak() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        let last_message = |contract: &MicroCowContract| {
+            contract.runtime.created_send_message_requests().pop().unwrap().message
+        };
+
+        receive_message(&mut contract, owner, Message::ClaimDailyReward { owner });
+        assert!(matches!(last_message(&contract), Message::DailyRewardClaimed { daily_reward, amount }
+            if daily_reward.streak == 1 && amount == Amount::from_tokens(DAILY_REWARD)));
+
+        // claiming again within the cooldown fails
+        contract.runtime.set_system_time(Timestamp::from(START_TIME + DAILY_REWARD_COOLDOWN - 1));
+        receive_message(&mut contract, owner, Message::ClaimDailyReward { owner });
+        assert!(matches!(last_message(&contract), Message::DailyRewardFailure { reason } if reason.contains("can be claimed again")));
+
+        // the next day continues the streak
+        contract.runtime.set_system_time(Timestamp::from(START_TIME + DAILY_REWARD_COOLDOWN));
+        receive_message(&mut contract, owner, Message::ClaimDailyReward { owner });
+        assert!(matches!(last_message(&contract), Message::DailyRewardClaimed { daily_reward, amount }
+            if daily_reward.streak == 2 && amount == Amount::from_tokens(DAILY_REWARD + DAILY_REWARD_STREAK_BONUS)));
+
+        // missing the streak window starts over
+        let late = START_TIME + 2 * DAILY_REWARD_COOLDOWN + DAILY_REWARD_STREAK_WINDOW;
+        contract.runtime.set_system_time(Timestamp::from(late));
+        receive_message(&mut contract, owner, Message::ClaimDailyReward { owner });
+        assert!(matches!(last_message(&contract), Message::DailyRewardClaimed { daily_reward, .. } if daily_reward.streak == 1));
+        assert_eq!(block_on(contract.state.get_daily_reward(owner)).unwrap().total_claimed,
+            Amount::from_tokens(3 * DAILY_REWARD + DAILY_REWARD_STREAK_BONUS));
+    }
+
+    #[test]
+    fn feed_type_changes_how_the_feeding_counts() {
+        let owner = test_owner("01");
+        let mut contract = player_contract(owner, START_TIME + ON_TIME_FEED + 1);
+        give_cow(&mut contract, "Bessie", START_TIME);
+        block_on(contract.state.add_feed(owner, FeedType::PremiumGrain, 2));
+
+        // PremiumGrain counts a late feeding as on time
+        block_on(contract.execute_operation(CowOperation::FeedCow { owner, cow_name: String::from("Bessie"), feed_type: FeedType::PremiumGrain }));
+
+        let cow = get_cow(&contract, "Bessie");
+        assert_eq!((cow.feeding_stats.on_time, cow.feeding_stats.late), (1, 0));
+        assert_eq!(block_on(contract.state.get_feed_inventory(owner)).count(FeedType::PremiumGrain), 1);
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(matches!(&requests[0].message, Message::FeedCow { feed_type: FeedType::PremiumGrain, .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = "not enough Supplement")]
+    fn feeding_needs_a_feed_item() {
+        let owner = test_owner("01");
+        let mut contract = player_contract(owner, START_TIME + WELL_FED + 1);
+        give_cow(&mut contract, "Bessie", START_TIME);
+        block_on(contract.state.add_feed(owner, FeedType::BasicHay, 1));
+        block_on(contract.execute_operation(CowOperation::FeedCow { owner, cow_name: String::from("Bessie"), feed_type: FeedType::Supplement }));
+    }
+
+    #[test]
+    fn root_sells_feed_and_refunds_the_excess_payment() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        let feed_cost = contract.state.get_feed_cost(FeedType::BasicHay, 3);
+        let payment = feed_cost.saturating_add(Amount::from_tokens(1));
+        receive_message(&mut contract, owner, Message::BuyFeed { owner, feed_type: FeedType::BasicHay, quantity: 3, payment });
+
+        assert_eq!(block_on(contract.state.get_feed_inventory(owner)).count(FeedType::BasicHay), 3);
+        assert_eq!(contract.state.herd_stats.get().treasury_inflow, feed_cost);
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(matches!(&requests[0].message, Message::FeedBought { amount, refund, feed_inventory, .. }
+            if *amount == feed_cost && *refund == Amount::from_tokens(1) && feed_inventory.count(FeedType::BasicHay) == 3));
+    }
+
+    #[test]
+    fn barn_capacity_rejects_an_extra_buy() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        let names = ["Bessie", "Daisy", "Clover", "Molly", "Rosie", "Lulu"];
+        for name in names {
+            let message = buy_cow_message(&contract, owner, name, CowBreed::Jersey);
+            receive_message(&mut contract, owner, message);
+        }
+
+        assert_eq!(block_on(contract.state.get_farm_status(owner, Timestamp::from(START_TIME))).herd_size, FARM_BASE_CAPACITY);
+        assert!(!block_on(contract.state.cow_db.contains_key(&String::from("Lulu"))).unwrap());
+        assert!(contract.runtime.created_send_message_requests().iter().any(|request| matches!(
+            &request.message,
+            Message::BuyFailure { cow_buy_params, reason, .. } if cow_buy_params.name == "Lulu" && reason.contains("barn is full")
+        )));
+
+        // upgrading the farm makes room for the extra cow
+        let payment = Amount::from_tokens(FARM_UPGRADE_COST);
+        receive_message(&mut contract, owner, Message::UpgradeFarm { owner, level: 2, payment });
+        assert_eq!(block_on(contract.state.get_farm_status(owner, Timestamp::from(START_TIME))).barn_capacity, FARM_BASE_CAPACITY + FARM_CAPACITY_PER_LEVEL);
+        let message = buy_cow_message(&contract, owner, "Lulu", CowBreed::Jersey);
+        receive_message(&mut contract, owner, message);
+        assert!(block_on(contract.state.cow_db.contains_key(&String::from("Lulu"))).unwrap());
+    }
+
+    #[test]
+    fn dead_cows_do_not_fill_the_barn() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        for name in ["Bessie", "Daisy", "Clover", "Molly", "Rosie"] {
+            let message = buy_cow_message(&contract, owner, name, CowBreed::Jersey);
+            receive_message(&mut contract, owner, message);
+        }
+
+        // the registry still counts the dead cows, the barn is checked against the living ones
+        let system_time = Timestamp::from(START_TIME + UNIX_MICROS_IN_24_HOURS + 1);
+        contract.runtime.set_system_time(system_time);
+        let message = buy_cow_message(&contract, owner, "Lulu", CowBreed::Jersey);
+        receive_message(&mut contract, owner, message);
+        assert!(block_on(contract.state.cow_db.contains_key(&String::from("Lulu"))).unwrap());
+        assert_eq!(block_on(contract.state.get_player(owner)).unwrap().herd_size, FARM_BASE_CAPACITY + 1);
+        assert_eq!(block_on(contract.state.get_farm_status(owner, system_time)).herd_size, 1);
+    }
+
+    #[test]
+    fn buying_milestones_are_awarded_once() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        receive_message(&mut contract, owner, Message::Subscribe);
+        contract.state.save_farm_level(owner, 2);
+        for (index, breed) in CowBreed::ALL.into_iter().enumerate() {
+            let message = buy_cow_message(&contract, owner, &format!("Cow {}", index), breed);
+            receive_message(&mut contract, owner, message);
+        }
+
+        let achievements = block_on(contract.state.get_achievements(owner));
+        let awarded: Vec<Achievement> = achievements.iter().map(|player_achievement| player_achievement.achievement).collect();
+        assert_eq!(awarded, vec![Achievement::FirstCow, Achievement::BreedCollector]);
+        assert_eq!(achievements[1].bonus, Amount::from_tokens(BREED_COLLECTOR_BONUS));
+
+        let requests = contract.runtime.created_send_message_requests();
+        let awarded_messages = requests.iter()
+            .filter(|request| matches!(&request.message, Message::AchievementAwarded { .. }))
+            .count();
+        assert_eq!(awarded_messages, 2);
+    }
+
+    #[test]
+    fn rename_moves_every_record_of_the_cow() {
+        let owner = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        let system_time = Timestamp::from(START_TIME);
+        give_root_cow(&mut contract, "Bessie", owner, START_TIME);
+        let bessie = String::from("Bessie");
+        block_on(contract.state.open_loan(owner, vec![bessie.clone()], Amount::from_tokens(100), system_time));
+        block_on(contract.state.open_policy(owner, bessie.clone(), Amount::from_tokens(25), Amount::from_tokens(500), system_time));
+
+        let payment = Amount::from_tokens(RENAME_FEE);
+        receive_message(&mut contract, owner, Message::RenameCow { owner, cow_name: bessie.clone(), new_name: String::from("Clover"), payment });
+
+        let clover = String::from("Clover");
+        assert!(!block_on(contract.state.cow_db.contains_key(&bessie)).unwrap());
+        assert_eq!(get_cow(&contract, "Clover").name, "Clover");
+        assert_eq!(block_on(contract.state.get_cow_loan(&bessie)), None);
+        assert_eq!(block_on(contract.state.get_cow_loan(&clover)).unwrap().cow_names, vec![clover.clone()]);
+        assert_eq!(block_on(contract.state.get_cow_policy(&bessie)), None);
+        assert_eq!(block_on(contract.state.get_cow_policy(&clover)).unwrap().cow_name, clover);
+        assert_eq!(contract.state.herd_stats.get().treasury_inflow, payment);
+
+        let requests = contract.runtime.created_send_message_requests();
+        assert!(requests.iter().any(|request| matches!(
+            &request.message,
+            Message::RenameSuccess { new_name, amount, .. } if new_name == "Clover" && *amount == payment
+        )));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not available")]
+    fn rename_needs_an_unused_name() {
+        let owner = test_owner("01");
+        let mut contract = player_contract(owner, START_TIME);
+        give_cow(&mut contract, "Bessie", START_TIME);
+        give_cow(&mut contract, "Clover", START_TIME);
+        block_on(contract.execute_operation(CowOperation::RenameCow { owner, cow_name: String::from("Bessie"), new_name: String::from("Clover") }));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not an admin")]
+    fn non_admin_cannot_set_the_quest_schedule() {
+        let player = test_owner("01");
+        let mut contract = root_contract(START_TIME);
+        contract.runtime.set_authenticated_signer(player);
+        let operation = CowOperation::AdminSetQuestSchedule { owner: player, quests: Vec::new(), quests_per_day: 1, start_time: None };
+        block_on(contract.execute_operation(operation));
+    }
+}
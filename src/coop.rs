@@ -0,0 +1,263 @@
+use linera_sdk::base::{Amount, CryptoHash, Owner, Timestamp};
+use crate::{CoopMember, CoopRule, Cooperative, CowData, FeedingStatus};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Cooperative]
+/// Root chain keeps every cooperative, its cows are owned by the cooperative herd owner.
+/// Deposits stay in the cooperative treasury, apart from the Root chain treasury,
+/// until they pay for a cow or feed. Sale proceeds go back to members by their deposit share.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_coop_herd_owner(&self, coop_id: u64) -> Owner {
+        // derived from the cooperative id, nobody holds its key to sign for it
+        Owner(CryptoHash::from([COOP_OWNER_TAG, 0, 0, coop_id]))
+    }
+
+    pub async fn get_cooperative(&self, coop_id: u64) -> Option<Cooperative> {
+        self.cooperatives.get(&coop_id).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cooperative Result");
+            })
+    }
+
+    pub async fn get_cooperatives(&self, owner: Option<Owner>) -> Vec<Cooperative> {
+        let mut cooperatives = Vec::new();
+        self.cooperatives
+            .for_each_index_value(|_, cooperative| {
+                if owner.is_none() || cooperative.members.iter().any(|member| Some(member.owner) == owner) {
+                    cooperatives.push(cooperative);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cooperatives");
+            });
+        cooperatives
+    }
+
+    pub fn create_cooperative(
+        &mut self,
+        founder: Owner,
+        name: String,
+        members: Vec<Owner>,
+        feed_rule: CoopRule,
+        trade_rule: CoopRule,
+        system_time: Timestamp,
+    ) -> Result<Cooperative, String> {
+        if name.trim().is_empty() || name.len() > COOP_NAME_MAX_LENGTH {
+            return Err(format!("cooperative name must be 1 to {} characters", COOP_NAME_MAX_LENGTH));
+        }
+
+        // founder is always a member, listed once
+        let mut member_list = vec![CoopMember { owner: founder, deposited: Amount::ZERO }];
+        for owner in members.into_iter() {
+            if member_list.iter().all(|member| member.owner != owner) {
+                member_list.push(CoopMember { owner, deposited: Amount::ZERO });
+            }
+        }
+        if member_list.len() < 2 || member_list.len() > COOP_MAX_MEMBERS {
+            return Err(format!("cooperative must have 2 to {} members", COOP_MAX_MEMBERS));
+        }
+
+        let coop_id = *self.next_coop_id.get() + 1;
+        self.next_coop_id.set(coop_id);
+        let cooperative = Cooperative {
+            id: coop_id,
+            name,
+            founder,
+            herd_owner: self.get_coop_herd_owner(coop_id),
+            members: member_list,
+            treasury: Amount::ZERO,
+            feed_rule,
+            trade_rule,
+            created_at: system_time,
+        };
+        self.save_cooperative(cooperative.clone());
+        Ok(cooperative)
+    }
+
+    pub fn save_cooperative(&mut self, cooperative: Cooperative) {
+        let coop_id = cooperative.id;
+        self.cooperatives
+            .insert(&coop_id, cooperative)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Cooperative");
+            });
+    }
+
+    pub fn remove_cooperative(&mut self, coop_id: u64) {
+        self.cooperatives.remove(&coop_id).unwrap_or_else(|_| {
+            panic!("unable to remove Cooperative");
+        });
+    }
+
+    pub async fn check_coop_member(&self, coop_id: u64, owner: Owner) -> Result<Cooperative, String> {
+        let cooperative = self.get_cooperative(coop_id).await
+            .ok_or_else(|| format!("cooperative {} is not exist", coop_id))?;
+        if cooperative.members.iter().all(|member| member.owner != owner) {
+            return Err(format!("{:?} is not a member of cooperative {}", owner, coop_id));
+        }
+        Ok(cooperative)
+    }
+
+    pub fn check_coop_rule(&self, cooperative: &Cooperative, owner: Owner, rule: CoopRule) -> Result<(), String> {
+        if rule == CoopRule::FounderOnly && owner != cooperative.founder {
+            return Err(format!("only the founder of cooperative {} is allowed", cooperative.id));
+        }
+        Ok(())
+    }
+
+    pub async fn get_coop_herd(&self, coop_id: u64, system_time: Timestamp) -> Vec<CowData> {
+        let herd_owner = self.get_coop_herd_owner(coop_id);
+        let mut herd = Vec::new();
+        self.cow_db
+            .for_each_index_value(|_, cow| {
+                if cow.owner == herd_owner && self.get_cow_status(&cow, system_time) != FeedingStatus::Dead {
+                    herd.push(cow);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cow db");
+            });
+        herd
+    }
+
+    pub async fn check_coop_herd_capacity(&self, coop_id: u64, system_time: Timestamp) -> Result<(), String> {
+        let herd_size = self.get_coop_herd(coop_id, system_time).await.len() as u64;
+        if herd_size >= COOP_MAX_COWS {
+            return Err(format!("cooperative {} herd is full, {} of {} cows", coop_id, herd_size, COOP_MAX_COWS));
+        }
+        Ok(())
+    }
+
+    pub async fn get_coop_cow(
+        &self,
+        cooperative: &Cooperative,
+        cow_name: &String,
+        system_time: Timestamp,
+    ) -> Result<CowData, String> {
+        let cow = self.cow_db.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        if cow.owner.ne(&cooperative.herd_owner) {
+            return Err(format!("{:?} is not owned by cooperative {}", cow_name, cooperative.id));
+        }
+        if self.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
+            return Err(format!("{:?} has died", cow_name));
+        }
+        Ok(cow)
+    }
+
+    pub fn spend_coop_treasury(&self, cooperative: &mut Cooperative, amount: Amount) -> Result<(), String> {
+        cooperative.treasury
+            .try_sub_assign(amount)
+            .map_err(|_| format!("cooperative {} treasury can't pay {}", cooperative.id, amount))
+    }
+
+    pub fn get_coop_payouts(&self, cooperative: &Cooperative, amount: Amount) -> Vec<(Owner, Amount)> {
+        // every member gets the same share of the amount as of all deposits,
+        // rounding leftover goes to the founder
+        let total_deposited = cooperative.members
+            .iter()
+            .fold(Amount::ZERO, |total, member| total.saturating_add(member.deposited));
+        let mut payouts: Vec<(Owner, Amount)> = Vec::new();
+        let mut paid = Amount::ZERO;
+        if total_deposited.gt(&Amount::ZERO) {
+            for member in cooperative.members.iter() {
+                let share = member.deposited
+                    .saturating_mul(PRECISION_100_PERCENT as u128)
+                    .saturating_div(total_deposited);
+                let payout = self.get_price_share(amount, share);
+                if payout.gt(&Amount::ZERO) {
+                    paid.saturating_add_assign(payout);
+                    payouts.push((member.owner, payout));
+                }
+            }
+        }
+
+        let leftover = amount.saturating_sub(paid);
+        if leftover.gt(&Amount::ZERO) {
+            match payouts.iter_mut().find(|(owner, _)| *owner == cooperative.founder) {
+                Some((_, payout)) => payout.saturating_add_assign(leftover),
+                None => payouts.push((cooperative.founder, leftover)),
+            }
+        }
+        payouts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::{CoopRule, Cooperative};
+    use crate::state::MicroCow;
+    use crate::test_utils::{load_state, test_owner};
+
+    fn test_cooperative(state: &mut MicroCow, deposits: &[u128]) -> Cooperative {
+        let founder = test_owner("01");
+        let members = vec![test_owner("02"), test_owner("03")];
+        let mut cooperative = state
+            .create_cooperative(founder, String::from("Green Valley"), members, CoopRule::AnyMember, CoopRule::AnyMember, Timestamp::from(0))
+            .unwrap();
+        for (member, deposit) in cooperative.members.iter_mut().zip(deposits.iter()) {
+            member.deposited = Amount::from_tokens(*deposit);
+        }
+        cooperative
+    }
+
+    #[test]
+    fn payouts_follow_deposit_shares() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let cooperative = test_cooperative(&mut state, &[100, 300, 600]);
+
+            let payouts = state.get_coop_payouts(&cooperative, Amount::from_tokens(1_000));
+            assert_eq!(payouts, vec![
+                (test_owner("01"), Amount::from_tokens(100)),
+                (test_owner("02"), Amount::from_tokens(300)),
+                (test_owner("03"), Amount::from_tokens(600)),
+            ]);
+        });
+    }
+
+    #[test]
+    fn payout_rounding_leftover_goes_to_founder() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let cooperative = test_cooperative(&mut state, &[100, 100, 100]);
+
+            // 33.33% of 100 is truncated to 33 tokens, the 1 token leftover goes to the founder
+            let payouts = state.get_coop_payouts(&cooperative, Amount::from_tokens(100));
+            assert_eq!(payouts, vec![
+                (test_owner("01"), Amount::from_tokens(34)),
+                (test_owner("02"), Amount::from_tokens(33)),
+                (test_owner("03"), Amount::from_tokens(33)),
+            ]);
+            let total = payouts.iter().fold(Amount::ZERO, |total, (_, payout)| total.saturating_add(*payout));
+            assert_eq!(total, Amount::from_tokens(100));
+        });
+    }
+
+    #[test]
+    fn payout_without_deposits_goes_to_founder() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let cooperative = test_cooperative(&mut state, &[0, 0, 0]);
+
+            let payouts = state.get_coop_payouts(&cooperative, Amount::from_tokens(50));
+            assert_eq!(payouts, vec![(test_owner("01"), Amount::from_tokens(50))]);
+            assert!(state.get_coop_payouts(&cooperative, Amount::ZERO).is_empty());
+        });
+    }
+}
@@ -0,0 +1,231 @@
+use linera_sdk::base::{Amount, Timestamp};
+use crate::{CowBreed, EventModifier, FeedingConfig, FeedingStats, SeasonalEvent};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Seasonal Events]
+/// Time-boxed events that change feeding time zones, rewards, penalty, and cow price.
+/// Modifiers of events that are active at the same time are stacked.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_seasonal_events(&self) -> Vec<SeasonalEvent> {
+        self.seasonal_events.get().clone()
+    }
+
+    pub fn get_active_events(&self, system_time: Timestamp) -> Vec<SeasonalEvent> {
+        self.get_seasonal_events()
+            .into_iter()
+            .filter(|event| event.start_time <= system_time && system_time < event.end_time)
+            .collect()
+    }
+
+    pub fn get_current_and_upcoming_events(&self, system_time: Timestamp) -> Vec<SeasonalEvent> {
+        let mut events: Vec<SeasonalEvent> = self.get_seasonal_events()
+            .into_iter()
+            .filter(|event| system_time < event.end_time)
+            .collect();
+        events.sort_by_key(|event| (event.start_time, event.id));
+        events
+    }
+
+    pub fn schedule_event(
+        &mut self,
+        name: String,
+        start_time: Timestamp,
+        end_time: Timestamp,
+        modifiers: Vec<EventModifier>,
+        system_time: Timestamp,
+    ) -> SeasonalEvent {
+        if start_time >= end_time {
+            panic!("event must start before it ends");
+        }
+        if end_time <= system_time {
+            panic!("event must end in the future");
+        }
+        if modifiers.is_empty() {
+            panic!("event must have at least 1 modifier");
+        }
+        for modifier in modifiers.iter() {
+            self.check_event_modifier(modifier);
+        }
+
+        let event_id = *self.next_event_id.get() + 1;
+        self.next_event_id.set(event_id);
+        let event = SeasonalEvent { id: event_id, name, start_time, end_time, modifiers };
+
+        // events that have ended are no longer kept
+        let mut events = self.get_current_and_upcoming_events(system_time);
+        events.push(event.clone());
+        self.seasonal_events.set(events);
+        event
+    }
+
+    pub fn cancel_event(&mut self, event_id: u64) {
+        let mut events = self.get_seasonal_events();
+        if !events.iter().any(|event| event.id == event_id) {
+            panic!("unable to find event {}", event_id);
+        }
+        events.retain(|event| event.id != event_id);
+        self.seasonal_events.set(events);
+    }
+
+    pub fn get_feeding_config_at(&self, system_time: Timestamp) -> FeedingConfig {
+        let mut config = self.get_game_config().feeding_config;
+        for event in self.get_active_events(system_time).iter() {
+            for modifier in event.modifiers.iter() {
+                match *modifier {
+                    EventModifier::OnTimeRewardMultiplier { multiplier } => {
+                        config.on_time_reward = self.get_event_share(config.on_time_reward, multiplier);
+                    }
+                    EventModifier::LateRewardMultiplier { multiplier } => {
+                        config.late_reward = self.get_event_share(config.late_reward, multiplier);
+                    }
+                    EventModifier::ForgotPenaltyMultiplier { multiplier } => {
+                        config.forgot_penalty = self.get_event_share(config.forgot_penalty, multiplier);
+                    }
+                    EventModifier::HungerWindowScale { scale } => {
+                        // death threshold stays, so an event can't kill a cow that was fed on time
+                        config.well_fed = self.get_event_share(config.well_fed, scale);
+                        config.on_time_feed = self.get_event_share(config.on_time_feed, scale);
+                        config.late_feed = self.get_event_share(config.late_feed, scale);
+                    }
+                    EventModifier::BreedDiscount { .. } => {}
+                }
+            }
+        }
+
+        // wider windows must still end before the cow starves
+        config.late_feed = config.late_feed.min(config.starving.saturating_sub(1));
+        config.on_time_feed = config.on_time_feed.min(config.late_feed.saturating_sub(1));
+        config.well_fed = config.well_fed.min(config.on_time_feed.saturating_sub(1));
+        config
+    }
+
+    pub fn get_event_feeding_bonus(&self, previous: &FeedingStats, current: &FeedingStats, system_time: Timestamp) -> i64 {
+        // events change the rewards & penalty of the feedings made while they are active,
+        // so the difference from the base config is kept with the cow at feeding time
+        let base_config = self.get_game_config().feeding_config;
+        let event_config = self.get_feeding_config_at(self.get_game_time(system_time));
+        let difference = |event_value: u64, base_value: u64| (event_value as i128) - (base_value as i128);
+        let on_time = current.on_time.saturating_sub(previous.on_time) as i128;
+        let late = current.late.saturating_sub(previous.late) as i128;
+        let forgot = current.forgot.saturating_sub(previous.forgot) as i128;
+        let bonus = on_time * difference(event_config.on_time_reward, base_config.on_time_reward)
+            + late * difference(event_config.late_reward, base_config.late_reward)
+            - forgot * difference(event_config.forgot_penalty, base_config.forgot_penalty);
+        bonus.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    pub fn get_event_cow_price(&self, breed: CowBreed, system_time: Timestamp) -> Amount {
+        let mut price = self.get_cow_price(breed);
+        for event in self.get_active_events(system_time).iter() {
+            for modifier in event.modifiers.iter() {
+                if let EventModifier::BreedDiscount { breed: discounted_breed, discount } = *modifier {
+                    if discounted_breed == breed {
+                        price = price.saturating_sub(self.get_price_share(price, discount as u128));
+                    }
+                }
+            }
+        }
+
+        // stacked discounts can't give the cow away
+        let price_floor = self.get_price_share(self.get_cow_price(breed), EVENT_PRICE_FLOOR);
+        price.max(price_floor)
+    }
+
+    fn check_event_modifier(&self, modifier: &EventModifier) {
+        match *modifier {
+            EventModifier::BreedDiscount { discount, .. } => {
+                if discount > PRECISION_100_PERCENT as u64 {
+                    panic!("discount must not exceed 100%");
+                }
+            }
+            EventModifier::HungerWindowScale { scale } => {
+                if scale == 0 {
+                    panic!("hunger window scale must be more than 0%");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn get_event_share(&self, value: u64, share: u64) -> u64 {
+        // share is using 2 digit decimal precision, where 100% equivalent to 10_000
+        let value = (value as u128) * (share as u128) / (PRECISION_100_PERCENT as u128);
+        value.min(u64::MAX as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, ChainId, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::{CowBreed, EventModifier};
+    use crate::constants::*;
+    use crate::state::MicroCow;
+    use crate::test_utils::{load_state, test_owner};
+
+    const START_TIME: u64 = 10 * UNIX_MICROS_IN_24_HOURS;
+
+    async fn root_state(store: &KeyValueStore) -> MicroCow {
+        let mut state = load_state(store).await;
+        state.initialize(test_owner("ad"), ChainId::root(0), Amount::from_tokens(100_000), true).await;
+        state
+    }
+
+    #[test]
+    fn active_event_modifiers_stack() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = root_state(&store).await;
+            let now = Timestamp::from(START_TIME);
+            let end = Timestamp::from(START_TIME + UNIX_MICROS_IN_24_HOURS);
+            let doubled = EventModifier::OnTimeRewardMultiplier { multiplier: 20_000 };
+            state.schedule_event(String::from("Spring"), now, end, vec![doubled], now);
+            state.schedule_event(String::from("Harvest"), now, end, vec![doubled], now);
+
+            let base_config = state.get_game_config().feeding_config;
+            let config = state.get_feeding_config_at(now);
+            assert_eq!(config.on_time_reward, 4 * base_config.on_time_reward);
+            assert_eq!(config.late_reward, base_config.late_reward);
+
+            // once the events end, the base config applies again
+            assert_eq!(state.get_feeding_config_at(end).on_time_reward, base_config.on_time_reward);
+        });
+    }
+
+    #[test]
+    fn breed_discount_only_applies_to_its_breed_while_active() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = root_state(&store).await;
+            let now = Timestamp::from(START_TIME);
+            let start = Timestamp::from(START_TIME + UNIX_MICROS_IN_1_HOURS);
+            let end = Timestamp::from(START_TIME + UNIX_MICROS_IN_24_HOURS);
+            let discount = EventModifier::BreedDiscount { breed: CowBreed::Jersey, discount: 2_500 };
+            state.schedule_event(String::from("Jersey Week"), start, end, vec![discount], now);
+
+            let jersey_price = state.get_cow_price(CowBreed::Jersey);
+            let holstein_price = state.get_cow_price(CowBreed::Holstein);
+            assert_eq!(state.get_event_cow_price(CowBreed::Jersey, now), jersey_price);
+            assert_eq!(
+                state.get_event_cow_price(CowBreed::Jersey, start),
+                jersey_price.saturating_sub(state.get_price_share(jersey_price, 2_500)),
+            );
+            assert_eq!(state.get_event_cow_price(CowBreed::Holstein, start), holstein_price);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "event must start before it ends")]
+    fn event_must_start_before_it_ends() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = root_state(&store).await;
+            let now = Timestamp::from(START_TIME);
+            let modifier = EventModifier::HungerWindowScale { scale: 5_000 };
+            state.schedule_event(String::from("Backwards"), now, now, vec![modifier], now);
+        });
+    }
+}
@@ -0,0 +1,78 @@
+use linera_sdk::base::{Amount, Owner, Timestamp};
+use crate::FarmStatus;
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Farm]
+/// Farm level of every player, its barn capacity caps how many cows the player can own.
+/// Root chain enforces the capacity, the player chain keeps its own level to check it early.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub async fn get_farm_level(&self, owner: Owner) -> u64 {
+        self.farm_levels.get(&owner).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Farm Level Result");
+            })
+            .unwrap_or(1)
+    }
+
+    pub fn get_barn_capacity(&self, level: u64) -> u64 {
+        FARM_BASE_CAPACITY + level.saturating_sub(1) * FARM_CAPACITY_PER_LEVEL
+    }
+
+    pub fn get_farm_upgrade_cost(&self, level: u64) -> Option<Amount> {
+        if level >= FARM_MAX_LEVEL {
+            return None;
+        }
+        Some(Amount::from_tokens(FARM_UPGRADE_COST * level as u128))
+    }
+
+    pub async fn get_farm_status(&self, owner: Owner, system_time: Timestamp) -> FarmStatus {
+        // root and player chains count the same living cows, so both agree on a full barn
+        let level = self.get_farm_level(owner).await;
+        FarmStatus {
+            owner,
+            level,
+            barn_capacity: self.get_barn_capacity(level),
+            herd_size: self.count_player_herd(owner, system_time).await,
+            next_upgrade_cost: self.get_farm_upgrade_cost(level),
+        }
+    }
+
+    pub async fn check_barn_capacity(&self, owner: Owner, system_time: Timestamp) -> Result<(), String> {
+        let barn_capacity = self.get_barn_capacity(self.get_farm_level(owner).await);
+
+        // registry herd size on root chain also counts dead cows, so the living cows are only counted
+        // when the barn looks full
+        let registry_herd_size = self.get_player_herd_size(owner).await;
+        if registry_herd_size.is_some_and(|herd_size| herd_size < barn_capacity) {
+            return Ok(());
+        }
+        let herd_size = self.count_player_herd(owner, system_time).await;
+        if herd_size >= barn_capacity {
+            return Err(format!(
+                "barn is full, {} of {} cows, upgrade the farm to get more room",
+                herd_size, barn_capacity,
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn check_farm_upgradable(&self, owner: Owner, level: u64) -> Result<Amount, String> {
+        // level is the one being upgraded to, so a stale upgrade won't be paid twice
+        let current_level = self.get_farm_level(owner).await;
+        if level != current_level + 1 {
+            return Err(format!("farm is at level {}, can't upgrade to level {}", current_level, level));
+        }
+        self.get_farm_upgrade_cost(current_level)
+            .ok_or_else(|| format!("farm is already at max level {}", FARM_MAX_LEVEL))
+    }
+
+    pub fn save_farm_level(&mut self, owner: Owner, level: u64) {
+        self.farm_levels
+            .insert(&owner, level)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Farm Level");
+            });
+    }
+}
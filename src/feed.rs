@@ -0,0 +1,84 @@
+use linera_sdk::base::{Amount, Owner};
+use crate::{FeedInventory, FeedPrice, FeedType, FeedingStats, FeedingStatus};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Feed Shop]
+/// Feed items bought from Root chain treasury, every feeding uses up 1 item.
+/// Root chain keeps its own copy of every inventory, so it can check the item was really used.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_feed_price(&self, feed_type: FeedType) -> Amount {
+        match feed_type {
+            FeedType::BasicHay => Amount::from_tokens(BASIC_HAY_PRICE),
+            FeedType::PremiumGrain => Amount::from_tokens(PREMIUM_GRAIN_PRICE),
+            FeedType::Supplement => Amount::from_tokens(SUPPLEMENT_PRICE),
+        }
+    }
+
+    pub fn get_feed_prices(&self) -> Vec<FeedPrice> {
+        FeedType::ALL
+            .into_iter()
+            .map(|feed_type| FeedPrice { feed_type, price: self.get_feed_price(feed_type) })
+            .collect()
+    }
+
+    pub fn get_feed_cost(&self, feed_type: FeedType, quantity: u64) -> Amount {
+        self.get_feed_price(feed_type)
+            .saturating_mul(quantity as u128)
+    }
+
+    pub async fn get_feed_inventory(&self, owner: Owner) -> FeedInventory {
+        self.feed_inventories.get(&owner).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Feed Inventory Result");
+            })
+            .unwrap_or_default()
+    }
+
+    pub async fn add_feed(&mut self, owner: Owner, feed_type: FeedType, quantity: u64) -> FeedInventory {
+        let mut feed_inventory = self.get_feed_inventory(owner).await;
+        let count = feed_inventory.count_mut(feed_type);
+        *count = count.saturating_add(quantity);
+        self.save_feed_inventory(owner, feed_inventory.clone());
+        feed_inventory
+    }
+
+    pub async fn use_feed(&mut self, owner: Owner, feed_type: FeedType, quantity: u64) -> Result<FeedInventory, String> {
+        let mut feed_inventory = self.get_feed_inventory(owner).await;
+        let count = feed_inventory.count_mut(feed_type);
+        if *count < quantity {
+            return Err(format!("not enough {:?}, {} left", feed_type, count));
+        }
+        *count -= quantity;
+        self.save_feed_inventory(owner, feed_inventory.clone());
+        Ok(feed_inventory)
+    }
+
+    pub fn save_feed_inventory(&mut self, owner: Owner, feed_inventory: FeedInventory) {
+        self.feed_inventories
+            .insert(&owner, feed_inventory)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Feed Inventory");
+            });
+    }
+
+    pub fn get_fed_feeding_stats(
+        &self,
+        mut feeding_stats: FeedingStats,
+        feeding_status: FeedingStatus,
+        feed_type: FeedType,
+    ) -> FeedingStats {
+        // PremiumGrain counts a LATE feeding as ON TIME
+        // Supplement counts an ON TIME feeding twice
+        match (feeding_status, feed_type) {
+            (FeedingStatus::OnTime, FeedType::Supplement) => feeding_stats.on_time += 2,
+            (FeedingStatus::OnTime, _) => feeding_stats.on_time += 1,
+            (FeedingStatus::Late, FeedType::PremiumGrain) => feeding_stats.on_time += 1,
+            (FeedingStatus::Late, _) => feeding_stats.late += 1,
+            (FeedingStatus::Forgot, _) => feeding_stats.forgot += 1,
+            (FeedingStatus::Full, _) | (FeedingStatus::Dead, _) => {}
+        }
+        feeding_stats
+    }
+}
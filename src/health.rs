@@ -0,0 +1,142 @@
+use linera_sdk::base::{Amount, Owner, Timestamp};
+use crate::{CowData, CowHealth, FeedingStats, FeedingStatus};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Cow Health]
+/// Sick cows lose appraisal value for every day of sickness,
+/// and die once SICKNESS_DEATH_TIME has passed, unless they are treated.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_cow_status(&self, cow: &CowData, system_time: Timestamp) -> FeedingStatus {
+        if let Some(sick_death_at) = self.get_sick_death_time(cow) {
+            if self.get_game_time(system_time) >= sick_death_at {
+                return FeedingStatus::Dead;
+            }
+        }
+        self.get_feeding_status(cow.last_fed_time, system_time)
+    }
+
+    pub fn get_sick_death_time(&self, cow: &CowData) -> Option<Timestamp> {
+        match cow.health {
+            CowHealth::Sick { sick_since } => Some(sick_since.saturating_add_micros(SICKNESS_DEATH_TIME)),
+            CowHealth::Healthy => None,
+        }
+    }
+
+    pub fn get_sickness_chance(&self, feeding_stats: &FeedingStats) -> u64 {
+        let chance = feeding_stats.late.saturating_mul(SICKNESS_LATE_CHANCE)
+            .saturating_add(feeding_stats.forgot.saturating_mul(SICKNESS_FORGOT_CHANCE));
+        chance.min(SICKNESS_MAX_CHANCE)
+    }
+
+    pub fn get_sickness_penalty(&self, cow: &CowData, cow_base_price: Amount, system_time: Timestamp) -> Amount {
+        // penalty is charged for every started 24 hours of sickness
+        let CowHealth::Sick { sick_since } = cow.health else {
+            return Amount::ZERO;
+        };
+        let system_time = self.get_game_time(system_time);
+        let sick_duration = system_time.micros().saturating_sub(sick_since.micros());
+        let days = sick_duration / UNIX_MICROS_IN_24_HOURS + 1;
+        let penalty_share = (SICKNESS_PENALTY * days as u128).min(PRECISION_100_PERCENT as u128);
+        self.get_price_share(cow_base_price, penalty_share)
+    }
+
+    pub fn get_treatment_price(&self) -> Amount {
+        Amount::from_tokens(TREATMENT_PRICE)
+    }
+
+    pub async fn check_cow_treatable(
+        &self,
+        owner: Owner,
+        cow_name: &String,
+        system_time: Timestamp,
+    ) -> Result<CowData, String> {
+        let cow = self.cow_db.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        if cow.owner.ne(&owner) {
+            return Err(format!("{:?} is not yours to treat", cow_name));
+        }
+        if self.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
+            return Err(format!("{:?} has died", cow_name));
+        }
+        if cow.health == CowHealth::Healthy {
+            return Err(format!("{:?} is not sick", cow_name));
+        }
+        Ok(cow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::{CowData, CowHealth, FeedingStats, FeedingStatus};
+    use crate::constants::*;
+    use crate::test_utils::{load_state, test_cow, test_owner};
+
+    fn health_cow(last_fed_time: u64, health: CowHealth) -> CowData {
+        let mut cow = test_cow("Bessie", test_owner("01"), last_fed_time);
+        cow.health = health;
+        cow
+    }
+
+    #[test]
+    fn sickness_penalty_grows_every_started_day() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = load_state(&store).await;
+            let base_price = Amount::from_tokens(1000);
+            let sick_since = Timestamp::from(1_000);
+            let sick_cow = health_cow(1_000, CowHealth::Sick { sick_since });
+            let penalty_at = |micros: u64| state.get_sickness_penalty(&sick_cow, base_price, sick_since.saturating_add_micros(micros));
+
+            assert_eq!(state.get_sickness_penalty(&health_cow(1_000, CowHealth::Healthy), base_price, sick_since), Amount::ZERO);
+            assert_eq!(penalty_at(0), Amount::from_tokens(100));
+            assert_eq!(penalty_at(UNIX_MICROS_IN_24_HOURS - 1), Amount::from_tokens(100));
+            assert_eq!(penalty_at(UNIX_MICROS_IN_24_HOURS), Amount::from_tokens(200));
+            assert_eq!(penalty_at(20 * UNIX_MICROS_IN_24_HOURS), base_price);
+        });
+    }
+
+    #[test]
+    fn sick_cow_dies_at_its_sick_death_time() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = load_state(&store).await;
+            let sick_since = Timestamp::from(1_000);
+            let death_at = sick_since.saturating_add_micros(SICKNESS_DEATH_TIME);
+            let sick_cow = health_cow(1_000, CowHealth::Sick { sick_since });
+            assert_eq!(state.get_sick_death_time(&sick_cow), Some(death_at));
+            assert_eq!(state.get_sick_death_time(&health_cow(1_000, CowHealth::Healthy)), None);
+
+            // a sick cow that keeps being fed still dies at its sick death time
+            let fed_sick_cow = health_cow(death_at.micros() - 1, CowHealth::Sick { sick_since });
+            assert_ne!(state.get_cow_status(&fed_sick_cow, Timestamp::from(death_at.micros() - 1)), FeedingStatus::Dead);
+            assert_eq!(state.get_cow_status(&fed_sick_cow, death_at), FeedingStatus::Dead);
+            let fed_healthy_cow = health_cow(death_at.micros() - 1, CowHealth::Healthy);
+            assert_ne!(state.get_cow_status(&fed_healthy_cow, death_at), FeedingStatus::Dead);
+
+            // feeding schedule shows the earlier death time
+            let schedule = state.get_feeding_schedule(&sick_cow, sick_since);
+            assert_eq!(schedule.death_at, sick_cow.last_fed_time.saturating_add_micros(UNIX_MICROS_IN_24_HOURS));
+            let schedule = state.get_feeding_schedule(&fed_sick_cow, sick_since);
+            assert_eq!(schedule.death_at, death_at);
+        });
+    }
+
+    #[test]
+    fn sickness_chance_is_capped() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = load_state(&store).await;
+            assert_eq!(state.get_sickness_chance(&FeedingStats::new()), 0);
+            assert_eq!(state.get_sickness_chance(&FeedingStats { on_time: 5, late: 2, forgot: 1 }), 900);
+            assert_eq!(state.get_sickness_chance(&FeedingStats { on_time: 0, late: 0, forgot: 20 }), SICKNESS_MAX_CHANCE);
+        });
+    }
+}
@@ -0,0 +1,231 @@
+use linera_sdk::base::{Amount, Owner, Timestamp};
+use crate::{CowData, FeedingStatus, InsurancePolicy, InsurancePolicyReport, InsuranceQuote};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Insurance]
+/// Policy that pays part of a cow sell value if the cow dies while the policy is active.
+/// A cow can only have 1 policy at a time.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub async fn get_policy(&self, policy_id: u64) -> Option<InsurancePolicy> {
+        self.insurance_policies.get(&policy_id).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Insurance Policy Result");
+            })
+    }
+
+    pub async fn get_policies(&self, owner: Option<Owner>) -> Vec<InsurancePolicy> {
+        let mut policies = Vec::new();
+        self.insurance_policies
+            .for_each_index_value(|_, policy| {
+                if owner.is_none() || owner == Some(policy.owner) {
+                    policies.push(policy);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read insurance policies");
+            });
+        policies
+    }
+
+    pub async fn get_cow_policy(&self, cow_name: &String) -> Option<InsurancePolicy> {
+        let policy_id = self.cow_policies.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow Policy Result");
+            })?;
+        self.get_policy(policy_id).await
+    }
+
+    pub async fn get_insurance_quote(&self, cow: CowData, system_time: Timestamp) -> InsuranceQuote {
+        // premium rate follows the cow feeding stats history
+        let stats = &cow.feeding_stats;
+        let premium_rate = INSURANCE_PREMIUM_RATE
+            - (stats.on_time as i128) * INSURANCE_ON_TIME_DISCOUNT
+            + (stats.late as i128) * INSURANCE_LATE_LOADING
+            + (stats.forgot as i128) * INSURANCE_FORGOT_LOADING;
+        let premium_rate = premium_rate.clamp(INSURANCE_MIN_PREMIUM_RATE, INSURANCE_MAX_PREMIUM_RATE);
+
+        // coverage is a share of the cow sell value
+        let cow_name = cow.name.clone();
+        let sell_value = self.cow_sell_value(cow, system_time).await;
+        let coverage = self.get_price_share(sell_value, INSURANCE_COVERAGE);
+        InsuranceQuote {
+            cow_name,
+            coverage,
+            premium_rate: premium_rate as u64,
+            premium: self.get_price_share(coverage, premium_rate as u128),
+            duration: INSURANCE_DURATION,
+        }
+    }
+
+    pub async fn check_cow_insurable(
+        &self,
+        owner: Owner,
+        cow_name: &String,
+        system_time: Timestamp,
+    ) -> Result<CowData, String> {
+        let cow = self.cow_db.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        if cow.owner.ne(&owner) {
+            return Err(format!("{:?} is not yours to insure", cow_name));
+        }
+        if self.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
+            return Err(format!("{:?} has died", cow_name));
+        }
+        if self.get_cow_policy(cow_name).await.is_some() {
+            return Err(format!("{:?} is already insured", cow_name));
+        }
+        Ok(cow)
+    }
+
+    pub async fn check_insurance_claimable(
+        &self,
+        policy: &InsurancePolicy,
+        system_time: Timestamp,
+    ) -> Result<(), String> {
+        let cow = self.cow_db.get(&policy.cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", policy.cow_name))?;
+        if cow.owner.ne(&policy.owner) {
+            return Err(format!("{:?} is no longer insured", policy.cow_name));
+        }
+
+        // cow must have died before the policy ends
+        let feeding_schedule = self.get_feeding_schedule(&cow, system_time);
+        if feeding_schedule.status != FeedingStatus::Dead {
+            return Err(format!("{:?} is still alive", policy.cow_name));
+        }
+        if feeding_schedule.death_at > policy.end_time {
+            return Err(format!("{:?} has died after the policy ended", policy.cow_name));
+        }
+        Ok(())
+    }
+
+    pub async fn get_policy_report(&self, policy: InsurancePolicy, system_time: Timestamp) -> InsurancePolicyReport {
+        let is_claimable = self.check_insurance_claimable(&policy, system_time).await.is_ok();
+        InsurancePolicyReport {
+            is_active: system_time <= policy.end_time,
+            is_claimable,
+            policy,
+        }
+    }
+
+    pub async fn open_policy(
+        &mut self,
+        owner: Owner,
+        cow_name: String,
+        premium: Amount,
+        coverage: Amount,
+        system_time: Timestamp,
+    ) -> InsurancePolicy {
+        let policy_id = *self.next_policy_id.get() + 1;
+        self.next_policy_id.set(policy_id);
+        let policy = InsurancePolicy {
+            id: policy_id,
+            owner,
+            cow_name,
+            premium,
+            coverage,
+            start_time: system_time,
+            end_time: system_time.saturating_add_micros(INSURANCE_DURATION),
+        };
+        self.save_policy(policy.clone());
+        policy
+    }
+
+    pub fn save_policy(&mut self, policy: InsurancePolicy) {
+        self.cow_policies.insert(&policy.cow_name, policy.id).unwrap_or_else(|_| {
+            panic!("Failed to insure {:?}", policy.cow_name);
+        });
+        self.insurance_policies.insert(&policy.id.clone(), policy).unwrap_or_else(|_| {
+            panic!("Failed to update Insurance Policy");
+        });
+    }
+
+    pub async fn resume_policies(&mut self, paused_at: Timestamp, paused_duration: u64) {
+        // coverage is extended by the paused duration, policy that ended before the game paused stays ended
+        for mut policy in self.get_policies(None).await.into_iter() {
+            if policy.end_time <= paused_at || policy.start_time > paused_at {
+                continue;
+            }
+            policy.end_time = policy.end_time.saturating_add_micros(paused_duration);
+            self.save_policy(policy);
+        }
+    }
+
+    pub fn close_policy(&mut self, policy: &InsurancePolicy) {
+        self.cow_policies.remove(&policy.cow_name).unwrap_or_else(|_| {
+            panic!("Failed to remove {:?} insurance", policy.cow_name);
+        });
+        self.insurance_policies.remove(&policy.id).unwrap_or_else(|_| {
+            panic!("Failed to remove Insurance Policy");
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::{CowData, FeedingStats};
+    use crate::test_utils::{load_state, test_cow, test_owner};
+
+    fn insured_cow(feeding_stats: FeedingStats) -> CowData {
+        let mut cow = test_cow("Bessie", test_owner("01"), 1_000);
+        cow.feeding_stats = feeding_stats;
+        cow
+    }
+
+    #[test]
+    fn new_cow_pays_the_base_premium_rate() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = load_state(&store).await;
+            let quote = state.get_insurance_quote(insured_cow(FeedingStats::new()), Timestamp::from(1_000)).await;
+            assert_eq!(quote.coverage, Amount::from_tokens(500));
+            assert_eq!(quote.premium_rate, 500);
+            assert_eq!(quote.premium, Amount::from_tokens(25));
+        });
+    }
+
+    #[test]
+    fn feeding_history_moves_the_premium_rate() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = load_state(&store).await;
+            let system_time = Timestamp::from(1_000);
+            let cow_with = |on_time: u64, late: u64, forgot: u64| insured_cow(FeedingStats { on_time, late, forgot });
+
+            assert_eq!(state.get_insurance_quote(cow_with(10, 0, 0), system_time).await.premium_rate, 300);
+            assert_eq!(state.get_insurance_quote(cow_with(0, 2, 1), system_time).await.premium_rate, 800);
+        });
+    }
+
+    #[test]
+    fn premium_rate_is_clamped() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = load_state(&store).await;
+            let system_time = Timestamp::from(1_000);
+
+            // a long on time history can't make the insurance free
+            let quote = state.get_insurance_quote(insured_cow(FeedingStats { on_time: 100, late: 0, forgot: 0 }), system_time).await;
+            assert_eq!(quote.premium_rate, 100);
+            assert_eq!(quote.premium, state.get_price_share(quote.coverage, 100));
+
+            // a neglected cow pays at most half of the coverage
+            let quote = state.get_insurance_quote(insured_cow(FeedingStats { on_time: 0, late: 0, forgot: 30 }), system_time).await;
+            assert_eq!(quote.premium_rate, 5_000);
+            assert_eq!(quote.premium, state.get_price_share(quote.coverage, 5_000));
+        });
+    }
+}
@@ -0,0 +1,179 @@
+use linera_sdk::base::{Amount, Owner, Timestamp};
+use crate::{CowData, FeedingStatus, KeeperBudget};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Keeper]
+/// Owner prepays a feeding budget for a cow, so any keeper chain can feed it ON TIME and earn a fee.
+/// The budget is kept apart from the Root chain treasury, only the feed price of every feeding goes in.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub async fn get_keeper_budget(&self, cow_name: &String) -> Option<KeeperBudget> {
+        self.keeper_budgets.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Keeper Budget Result");
+            })
+    }
+
+    pub async fn get_keeper_budgets(&self, owner: Option<Owner>) -> Vec<KeeperBudget> {
+        let mut keeper_budgets = Vec::new();
+        self.keeper_budgets
+            .for_each_index_value(|_, keeper_budget| {
+                if owner.is_none() || owner == Some(keeper_budget.owner) {
+                    keeper_budgets.push(keeper_budget);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read keeper budgets");
+            });
+        keeper_budgets
+    }
+
+    pub fn get_keeper_feeding_cost(&self, keeper_budget: &KeeperBudget) -> Amount {
+        self.get_feed_price(keeper_budget.feed_type)
+            .saturating_add(Amount::from_tokens(KEEPER_FEE))
+    }
+
+    pub async fn check_keeper_fundable(&self, owner: Owner, cow_name: &String, system_time: Timestamp) -> Result<(), String> {
+        let cow = self.cow_db.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        if cow.owner.ne(&owner) {
+            return Err(format!("{:?} is not yours to fund", cow_name));
+        }
+        if self.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
+            return Err(format!("{:?} has died", cow_name));
+        }
+
+        // budget left by a previous owner must be withdrawn first
+        if let Some(keeper_budget) = self.get_keeper_budget(cow_name).await {
+            if keeper_budget.owner.ne(&owner) {
+                return Err(format!("{:?} still has a budget of its previous owner", cow_name));
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn check_keeper_feedable(
+        &self,
+        cow_name: &String,
+        system_time: Timestamp,
+    ) -> Result<(KeeperBudget, CowData), String> {
+        let keeper_budget = self.get_keeper_budget(cow_name).await
+            .ok_or_else(|| format!("{:?} has no keeper budget", cow_name))?;
+        let cow = self.cow_db.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            })
+            .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+        if cow.owner.ne(&keeper_budget.owner) {
+            return Err(format!("{:?} is no longer owned by the budget owner", cow_name));
+        }
+
+        // keeper only feeds in the ON TIME window, so an auto-fed cow is never fed LATE
+        let feeding_status = self.get_cow_status(&cow, system_time);
+        if feeding_status != FeedingStatus::OnTime {
+            return Err(format!("{:?} is not in its on time window, {:?}", cow_name, feeding_status));
+        }
+        let feeding_cost = self.get_keeper_feeding_cost(&keeper_budget);
+        if keeper_budget.balance.lt(&feeding_cost) {
+            return Err(format!("{:?} keeper budget can't pay {}", cow_name, feeding_cost));
+        }
+        Ok((keeper_budget, cow))
+    }
+
+    pub async fn get_keeper_jobs(&self, system_time: Timestamp) -> Vec<KeeperBudget> {
+        let mut keeper_jobs = Vec::new();
+        for keeper_budget in self.get_keeper_budgets(None).await.into_iter() {
+            if self.check_keeper_feedable(&keeper_budget.cow_name, system_time).await.is_ok() {
+                keeper_jobs.push(keeper_budget);
+            }
+        }
+        keeper_jobs
+    }
+
+    pub fn save_keeper_budget(&mut self, keeper_budget: KeeperBudget) {
+        let cow_name = keeper_budget.cow_name.clone();
+        self.keeper_budgets
+            .insert(&cow_name, keeper_budget)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Keeper Budget");
+            });
+    }
+
+    pub fn remove_keeper_budget(&mut self, cow_name: &String) {
+        self.keeper_budgets.remove(cow_name).unwrap_or_else(|_| {
+            panic!("unable to remove Keeper Budget");
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::{FeedType, KeeperBudget};
+    use crate::test_utils::{load_state, test_cow, test_owner};
+
+    #[test]
+    fn renamed_cow_keeps_its_budget() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let owner = test_owner("01");
+            let system_time = Timestamp::from(1_000);
+            let cow_name = String::from("Molly");
+            let cow = test_cow(&cow_name, owner, system_time.micros());
+            state.create_new_or_update_cow_data(cow_name.clone(), cow).await;
+            state.save_keeper_budget(KeeperBudget {
+                cow_name: cow_name.clone(),
+                owner,
+                feed_type: FeedType::BasicHay,
+                balance: Amount::from_tokens(30),
+                feed_count: 0,
+                last_keeper: None,
+                last_fed_at: None,
+            });
+
+            let new_name = String::from("Dolly");
+            state.rename_cow(cow_name.clone(), new_name.clone()).await;
+            assert!(state.get_keeper_budget(&cow_name).await.is_none());
+            let keeper_budget = state.get_keeper_budget(&new_name).await.unwrap();
+            assert_eq!(keeper_budget.cow_name, new_name);
+            assert_eq!(keeper_budget.balance, Amount::from_tokens(30));
+            assert!(state.check_keeper_fundable(owner, &new_name, system_time).await.is_ok());
+        });
+    }
+
+    #[test]
+    fn budget_of_previous_owner_blocks_funding() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let (owner, new_owner) = (test_owner("01"), test_owner("02"));
+            let system_time = Timestamp::from(1_000);
+            let cow_name = String::from("Molly");
+            let cow = test_cow(&cow_name, new_owner, system_time.micros());
+            state.create_new_or_update_cow_data(cow_name.clone(), cow).await;
+            state.save_keeper_budget(KeeperBudget {
+                cow_name: cow_name.clone(),
+                owner,
+                feed_type: FeedType::BasicHay,
+                balance: Amount::from_tokens(30),
+                feed_count: 0,
+                last_keeper: None,
+                last_fed_at: None,
+            });
+            assert!(state.check_keeper_fundable(new_owner, &cow_name, system_time).await.is_err());
+
+            // root chain closes the budget when the cow changes hands
+            state.remove_keeper_budget(&cow_name);
+            assert!(state.check_keeper_fundable(new_owner, &cow_name, system_time).await.is_ok());
+        });
+    }
+}
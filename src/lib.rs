@@ -1,3 +1,24 @@
+pub mod state;
+pub mod constants;
+pub mod pricing;
+pub mod supply;
+pub mod treasury;
+pub mod migration;
+pub mod events;
+pub mod rewards;
+pub mod loans;
+pub mod insurance;
+pub mod feed;
+pub mod farm;
+pub mod health;
+pub mod achievements;
+pub mod quests;
+pub mod coop;
+pub mod caretaker;
+pub mod keeper;
+#[cfg(test)]
+mod test_utils;
+
 use std::str::FromStr;
 use async_graphql::{Request, Response, scalar};
 use async_graphql_derive::{SimpleObject};
@@ -24,6 +45,19 @@ pub enum CowOperation {
     Initialize,
     DeleteBuyNotification,
     DeleteSellNotification,
+    DeleteFeedNotification,
+    DeleteRewardNotification,
+    DeleteLoanNotification,
+    DeleteInsuranceNotification,
+    DeleteShopNotification,
+    DeleteFarmNotification,
+    DeleteHealthNotification,
+    DeleteAchievementNotification,
+    DeleteQuestNotification,
+    DeleteRenameNotification,
+    DeleteCoopNotification,
+    DeleteCaretakerNotification,
+    DeleteKeeperNotification,
     Subscribe,
     BuryDeadCows,
     BuyCow {
@@ -32,15 +66,240 @@ pub enum CowOperation {
         cow_id: String,
         cow_breed: CowBreed,
     },
+    BuyFeed {
+        owner: Owner,
+        feed_type: FeedType,
+        quantity: u64,
+    },
     FeedCow {
         owner: Owner,
         cow_name: String,
+        feed_type: FeedType,
+    },
+    FeedCows {
+        owner: Owner,
+        cow_names: Vec<String>,
+        feed_type: FeedType,
+    },
+    FeedAllCows {
+        owner: Owner,
+        feed_type: FeedType,
     },
     SellCow {
         owner: Owner,
         cow_name: String,
         cow_born_time: Timestamp,
     },
+    SellCows {
+        owner: Owner,
+        cow_names: Vec<String>,
+    },
+    ClaimDailyReward {
+        owner: Owner,
+    },
+    TakeLoan {
+        owner: Owner,
+        cow_names: Vec<String>,
+        amount: Amount,
+    },
+    RepayLoan {
+        owner: Owner,
+        loan_id: u64,
+    },
+    BuyInsurance {
+        owner: Owner,
+        cow_name: String,
+    },
+    ClaimInsurance {
+        owner: Owner,
+        cow_name: String,
+    },
+    UpgradeFarm {
+        owner: Owner,
+    },
+    TreatCow {
+        owner: Owner,
+        cow_name: String,
+    },
+    ClaimQuestReward {
+        owner: Owner,
+        quest_index: u64,
+    },
+    RenameCow {
+        owner: Owner,
+        cow_name: String,
+        new_name: String,
+    },
+    CreateCoop {
+        owner: Owner,
+        name: String,
+        members: Vec<Owner>,
+        feed_rule: CoopRule,
+        trade_rule: CoopRule,
+    },
+    DepositToCoop {
+        owner: Owner,
+        coop_id: u64,
+        amount: Amount,
+    },
+    CoopBuyCow {
+        owner: Owner,
+        coop_id: u64,
+        cow_name: String,
+        cow_id: String,
+        cow_breed: CowBreed,
+    },
+    CoopFeedCow {
+        owner: Owner,
+        coop_id: u64,
+        cow_name: String,
+        feed_type: FeedType,
+    },
+    CoopSellCow {
+        owner: Owner,
+        coop_id: u64,
+        cow_name: String,
+    },
+    DissolveCoop {
+        owner: Owner,
+        coop_id: u64,
+    },
+    GrantCaretaker {
+        owner: Owner,
+        caretaker: Owner,
+        cow_names: Vec<String>,
+        expires_at: Timestamp,
+    },
+    RevokeCaretaker {
+        owner: Owner,
+        caretaker: Owner,
+    },
+    CaretakerFeedCow {
+        owner: Owner,
+        cow_name: String,
+        feed_type: FeedType,
+    },
+    FundKeeperBudget {
+        owner: Owner,
+        cow_name: String,
+        feed_type: FeedType,
+        amount: Amount,
+    },
+    WithdrawKeeperBudget {
+        owner: Owner,
+        cow_name: String,
+    },
+    KeeperFeed {
+        owner: Owner,
+        cow_name: String,
+    },
+    // executed by Root chain admin
+    AdminTopUpTreasury {
+        owner: Owner,
+        amount: Amount,
+    },
+    AdminSetCowPrice {
+        owner: Owner,
+        cow_breed: CowBreed,
+        price: Amount,
+    },
+    AdminSetFeedingConfig {
+        owner: Owner,
+        feeding_config: FeedingConfig,
+    },
+    AdminRenameCow {
+        owner: Owner,
+        cow_name: String,
+        new_name: String,
+    },
+    AdminRemoveCow {
+        owner: Owner,
+        cow_name: String,
+    },
+    AdminAddAdmin {
+        owner: Owner,
+        admin: Owner,
+    },
+    AdminRemoveAdmin {
+        owner: Owner,
+        admin: Owner,
+    },
+    AdminPauseGame {
+        owner: Owner,
+        reason: String,
+    },
+    AdminResumeGame {
+        owner: Owner,
+    },
+    AdminSetMinReserveRatio {
+        owner: Owner,
+        min_reserve_ratio: u64,
+    },
+    AdminSetPriceBounds {
+        owner: Owner,
+        cow_breed: CowBreed,
+        min_price: Amount,
+        max_price: Amount,
+    },
+    AdminSetPopulationCap {
+        owner: Owner,
+        cow_breed: CowBreed,
+        population_cap: Option<u64>,
+    },
+    AdminReleaseLimitedEdition {
+        owner: Owner,
+        cow_breed: CowBreed,
+        total_supply: u64,
+    },
+    AdminScheduleEvent {
+        owner: Owner,
+        name: String,
+        start_time: Timestamp,
+        end_time: Timestamp,
+        modifiers: Vec<EventModifier>,
+    },
+    AdminCancelEvent {
+        owner: Owner,
+        event_id: u64,
+    },
+    AdminSeizeDefaultedLoans {
+        owner: Owner,
+    },
+    AdminSetQuestSchedule {
+        owner: Owner,
+        quests: Vec<Quest>,
+        quests_per_day: u64,
+        start_time: Option<Timestamp>,
+    },
+    AdminClearQuestSchedule {
+        owner: Owner,
+    },
+}
+
+impl CowOperation {
+    pub fn is_admin_operation(&self) -> bool {
+        matches!(
+            self,
+            CowOperation::AdminTopUpTreasury { .. }
+                | CowOperation::AdminSetCowPrice { .. }
+                | CowOperation::AdminSetFeedingConfig { .. }
+                | CowOperation::AdminRenameCow { .. }
+                | CowOperation::AdminRemoveCow { .. }
+                | CowOperation::AdminAddAdmin { .. }
+                | CowOperation::AdminRemoveAdmin { .. }
+                | CowOperation::AdminPauseGame { .. }
+                | CowOperation::AdminResumeGame { .. }
+                | CowOperation::AdminSetMinReserveRatio { .. }
+                | CowOperation::AdminSetPriceBounds { .. }
+                | CowOperation::AdminSetPopulationCap { .. }
+                | CowOperation::AdminReleaseLimitedEdition { .. }
+                | CowOperation::AdminScheduleEvent { .. }
+                | CowOperation::AdminCancelEvent { .. }
+                | CowOperation::AdminSeizeDefaultedLoans { .. }
+                | CowOperation::AdminSetQuestSchedule { .. }
+                | CowOperation::AdminClearQuestSchedule { .. }
+        )
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -50,21 +309,136 @@ pub enum Message {
         owner: Owner,
         cow_buy_params: CowBuyParams,
     },
+    BuyFeed {
+        owner: Owner,
+        feed_type: FeedType,
+        quantity: u64,
+        payment: Amount,
+    },
     FeedCow {
         owner: Owner,
         cow_data: CowData,
+        feed_type: FeedType,
     },
     SellCow {
         owner: Owner,
         cow_name: String,
     },
+    FeedCows {
+        owner: Owner,
+        cows: Vec<CowData>,
+        feed_type: FeedType,
+    },
+    SellCows {
+        owner: Owner,
+        cow_names: Vec<String>,
+    },
+    ClaimDailyReward {
+        owner: Owner,
+    },
+    TakeLoan {
+        owner: Owner,
+        cow_names: Vec<String>,
+        amount: Amount,
+    },
+    RepayLoan {
+        owner: Owner,
+        loan_id: u64,
+        payment: Amount,
+    },
+    BuyInsurance {
+        owner: Owner,
+        cow_name: String,
+        payment: Amount,
+    },
+    ClaimInsurance {
+        owner: Owner,
+        cow_name: String,
+    },
+    UpgradeFarm {
+        owner: Owner,
+        level: u64,
+        payment: Amount,
+    },
+    TreatCow {
+        owner: Owner,
+        cow_name: String,
+        payment: Amount,
+    },
+    ClaimQuestReward {
+        owner: Owner,
+        quest_index: u64,
+    },
+    RenameCow {
+        owner: Owner,
+        cow_name: String,
+        new_name: String,
+        payment: Amount,
+    },
+    CreateCoop {
+        owner: Owner,
+        name: String,
+        members: Vec<Owner>,
+        feed_rule: CoopRule,
+        trade_rule: CoopRule,
+    },
+    DepositToCoop {
+        owner: Owner,
+        coop_id: u64,
+        amount: Amount,
+    },
+    CoopBuyCow {
+        owner: Owner,
+        coop_id: u64,
+        cow_buy_params: CowBuyParams,
+    },
+    CoopFeedCow {
+        owner: Owner,
+        coop_id: u64,
+        cow_name: String,
+        feed_type: FeedType,
+    },
+    CoopSellCow {
+        owner: Owner,
+        coop_id: u64,
+        cow_name: String,
+    },
+    DissolveCoop {
+        owner: Owner,
+        coop_id: u64,
+    },
+    GrantCaretaker {
+        owner: Owner,
+        caretaker: Owner,
+        cow_names: Vec<String>,
+        expires_at: Timestamp,
+    },
+    RevokeCaretaker {
+        owner: Owner,
+        caretaker: Owner,
+    },
+    FundKeeperBudget {
+        owner: Owner,
+        cow_name: String,
+        feed_type: FeedType,
+        amount: Amount,
+    },
+    WithdrawKeeperBudget {
+        owner: Owner,
+        cow_name: String,
+    },
+    KeeperFeed {
+        owner: Owner,
+        cow_name: String,
+    },
     // executed by User chain
     BuySuccess {
         cow_data: CowData,
     },
     BuyFailure {
-        cow_data: CowData,
+        cow_data: Option<CowData>,
         cow_buy_params: CowBuyParams,
+        reason: String,
     },
     SellSuccess {
         cow_name: String,
@@ -78,6 +452,216 @@ pub enum Message {
     FeedSuccess {
         cow_data: CowData,
     },
+    FeedCowsSuccess {
+        cows: Vec<CowData>,
+    },
+    FeedFailure {
+        cows: Vec<CowData>,
+        reason: String,
+        feed_inventory: FeedInventory,
+    },
+    FeedBought {
+        feed_type: FeedType,
+        quantity: u64,
+        amount: Amount,
+        refund: Amount,
+        feed_inventory: FeedInventory,
+    },
+    FeedShopFailure {
+        feed_type: FeedType,
+        quantity: u64,
+        reason: String,
+        refund: Amount,
+    },
+    SellCowsSuccess {
+        sales: Vec<CowSale>,
+    },
+    SellCowsFailure {
+        failures: Vec<SellNotif>,
+    },
+    DailyRewardClaimed {
+        daily_reward: DailyReward,
+        amount: Amount,
+    },
+    DailyRewardFailure {
+        reason: String,
+    },
+    LoanApproved {
+        loan: Loan,
+    },
+    LoanRepaid {
+        loan_id: u64,
+        refund: Amount,
+    },
+    LoanFailure {
+        loan_id: Option<u64>,
+        reason: String,
+        refund: Amount,
+    },
+    LoanSeized {
+        loan: Loan,
+    },
+    LoanClosed {
+        loan: Loan,
+        reason: String,
+    },
+    InsuranceIssued {
+        policy: InsurancePolicy,
+        refund: Amount,
+    },
+    InsurancePaid {
+        policy: InsurancePolicy,
+        payout: Amount,
+    },
+    InsuranceFailure {
+        cow_name: String,
+        reason: String,
+        refund: Amount,
+    },
+    InsuranceClosed {
+        policy: InsurancePolicy,
+        reason: String,
+    },
+    FarmUpgraded {
+        level: u64,
+        amount: Amount,
+        refund: Amount,
+    },
+    FarmUpgradeFailure {
+        level: u64,
+        reason: String,
+        refund: Amount,
+    },
+    CowTreated {
+        cow_data: CowData,
+        amount: Amount,
+        refund: Amount,
+    },
+    TreatmentFailure {
+        cow_name: String,
+        reason: String,
+        refund: Amount,
+    },
+    CowHealthUpdated {
+        cow_data: CowData,
+    },
+    AchievementAwarded {
+        player_achievement: PlayerAchievement,
+    },
+    QuestRewardPaid {
+        quest_index: u64,
+        quest_progress: QuestProgress,
+        amount: Amount,
+    },
+    QuestFailure {
+        quest_index: u64,
+        reason: String,
+    },
+    QuestScheduleUpdated {
+        quest_schedule: Option<QuestSchedule>,
+    },
+    RenameSuccess {
+        cow_name: String,
+        new_name: String,
+        amount: Amount,
+        refund: Amount,
+    },
+    RenameFailure {
+        cow_name: String,
+        new_name: String,
+        reason: String,
+        refund: Amount,
+    },
+    CoopSuccess {
+        coop_id: u64,
+        action: CoopAction,
+        cow_name: String,
+        amount: Amount,
+    },
+    CoopFailure {
+        coop_id: u64,
+        action: CoopAction,
+        cow_name: String,
+        reason: String,
+        refund: Amount,
+    },
+    CoopPayout {
+        coop_id: u64,
+        action: CoopAction,
+        cow_name: String,
+        amount: Amount,
+    },
+    CaretakerGranted {
+        grant: CaretakerGrant,
+    },
+    CaretakerRevoked {
+        owner: Owner,
+        caretaker: Owner,
+    },
+    CaretakerFailure {
+        caretaker: Owner,
+        action: CaretakerAction,
+        reason: String,
+    },
+    CaretakerFed {
+        caretaker: Owner,
+        cow_data: CowData,
+    },
+    KeeperBudgetUpdated {
+        keeper_budget: KeeperBudget,
+        amount: Amount,
+    },
+    KeeperBudgetClosed {
+        cow_name: String,
+        refund: Amount,
+    },
+    KeeperFed {
+        cow_data: CowData,
+        keeper_budget: KeeperBudget,
+        amount: Amount,
+    },
+    KeeperFeePaid {
+        cow_name: String,
+        fee: Amount,
+    },
+    KeeperFailure {
+        cow_name: String,
+        action: KeeperAction,
+        reason: String,
+        refund: Amount,
+    },
+    TreasuryToppedUp {
+        amount: Amount,
+    },
+    GameConfigUpdated {
+        game_config: GameConfig,
+    },
+    ReserveRatioUpdated {
+        min_reserve_ratio: u64,
+    },
+    BreedSuppliesUpdated {
+        breed_supplies: Vec<BreedSupply>,
+    },
+    CowRenamed {
+        cow_name: String,
+        new_name: String,
+    },
+    CowRemoved {
+        cow_name: String,
+    },
+    AdminsUpdated {
+        admins: Vec<Owner>,
+    },
+    GamePaused {
+        pause_state: PauseState,
+    },
+    GameResumed {
+        paused_at: Timestamp,
+        resumed_at: Timestamp,
+    },
+    SeasonalEventsUpdated {
+        seasonal_events: Vec<SeasonalEvent>,
+    },
     Subscribe,
 }
 
@@ -136,7 +720,6 @@ pub struct MicroCowParameters {
 
 /// [CowBreed]
 /// ------------------------------------------------------------------------------------------
-scalar!(CowBreed);
 #[derive(Debug, Clone, Copy, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
 pub enum CowBreed {
     Jersey,
@@ -147,18 +730,31 @@ pub enum CowBreed {
     Simmental,
 }
 
+scalar!(CowBreed);
+
+impl CowBreed {
+    pub const ALL: [CowBreed; 6] = [
+        CowBreed::Jersey,
+        CowBreed::Limousin,
+        CowBreed::Hallikar,
+        CowBreed::Hereford,
+        CowBreed::Holstein,
+        CowBreed::Simmental,
+    ];
+}
+
 /// [CowGender]
 /// ------------------------------------------------------------------------------------------
-scalar!(CowGender);
 #[derive(Debug, Clone, Copy, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
 pub enum CowGender {
     Male,
     Female,
 }
 
+scalar!(CowGender);
+
 /// [CowBuyParams]
 /// ------------------------------------------------------------------------------------------
-scalar!(CowBuyParams);
 #[derive(Debug, Clone, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
 pub struct CowBuyParams {
     pub id: String,
@@ -168,6 +764,8 @@ pub struct CowBuyParams {
     pub price: Amount,
 }
 
+scalar!(CowBuyParams);
+
 impl CowBuyParams {
     pub fn new(_id: String, _name: String, _breed: CowBreed, _gender: CowGender, _price: Amount) -> Self {
         Self {
@@ -181,8 +779,9 @@ impl CowBuyParams {
 }
 
 /// [CowData]
+/// event_bonus is the appraisal multiplier that seasonal events added or removed at each feeding,
+/// using 2 digit decimal precision, 100% equivalent to 10_000
 /// ------------------------------------------------------------------------------------------
-scalar!(CowData);
 #[derive(Debug, Clone, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
 pub struct CowData {
     pub id: String,
@@ -193,9 +792,14 @@ pub struct CowData {
     pub last_fed_time: Timestamp,
     pub feeding_stats: FeedingStats,
     pub owner: Owner,
+    pub health: CowHealth,
+    pub event_bonus: i64,
 }
 
+scalar!(CowData);
+
 impl CowData {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         _id: String,
         _name: String,
@@ -215,29 +819,94 @@ impl CowData {
             last_fed_time: _last_fed_time,
             feeding_stats: _feeding_stats,
             owner: _owner,
+            health: CowHealth::Healthy,
+            event_bonus: 0,
         }
     }
 }
 
 /// [FeedingStats]
 /// ------------------------------------------------------------------------------------------
-scalar!(FeedingStats);
-#[derive(Debug, Clone, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
 pub struct FeedingStats {
     pub on_time: u64,
     pub late: u64,
     pub forgot: u64,
 }
 
+scalar!(FeedingStats);
+
 impl FeedingStats {
     pub fn new() -> Self { Self { on_time: 0, late: 0, forgot: 0 } }
 }
 
-/// [BuyNotif]
+/// [CowHealth]
+/// Cows fed LATE or FORGOT have a chance to fall ill, a sick cow dies early unless it's treated.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum CowHealth {
+    #[default]
+    Healthy,
+    Sick {
+        sick_since: Timestamp,
+    },
+}
+
+scalar!(CowHealth);
+
+/// [CowSale]
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub struct CowSale {
+    pub cow_name: String,
+    pub cow_owner: Owner,
+    pub payment: Amount,
+}
+
+/// [FeedingStatus]
+/// Feeding time zone of a cow, measured from its last fed time.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum FeedingStatus {
+    Full,
+    OnTime,
+    Late,
+    Forgot,
+    Dead,
+}
+
+scalar!(FeedingStatus);
+
+/// [FeedType]
+/// Feed item sold in the feed shop, 1 item is used up for every feeding.
+/// PremiumGrain widens the ON TIME window, a LATE feeding counts as ON TIME.
+/// Supplement improves the appraisal, an ON TIME feeding counts twice.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum FeedType {
+    #[default]
+    BasicHay,
+    PremiumGrain,
+    Supplement,
+}
+
+scalar!(FeedType);
+
+impl FeedType {
+    pub const ALL: [FeedType; 3] = [
+        FeedType::BasicHay,
+        FeedType::PremiumGrain,
+        FeedType::Supplement,
+    ];
+}
+
+/// [FeedInventory]
+/// Feed items owned by a player, kept by Root chain and the player's own chain.
 /// ------------------------------------------------------------------------------------------
 #[derive(
     Debug,
     Clone,
+    Default,
     Deserialize,
     Eq,
     Ord,
@@ -246,21 +915,31 @@ impl FeedingStats {
     Serialize,
     SimpleObject
 )]
-pub struct BuyNotif {
-    pub cow_name: String,
-    pub is_success: bool,
+pub struct FeedInventory {
+    pub basic_hay: u64,
+    pub premium_grain: u64,
+    pub supplement: u64,
 }
 
-impl Default for BuyNotif {
-    fn default() -> Self {
-        Self {
-            cow_name: String::from(""),
-            is_success: false,
+impl FeedInventory {
+    pub fn count(&self, feed_type: FeedType) -> u64 {
+        match feed_type {
+            FeedType::BasicHay => self.basic_hay,
+            FeedType::PremiumGrain => self.premium_grain,
+            FeedType::Supplement => self.supplement,
+        }
+    }
+
+    pub fn count_mut(&mut self, feed_type: FeedType) -> &mut u64 {
+        match feed_type {
+            FeedType::BasicHay => &mut self.basic_hay,
+            FeedType::PremiumGrain => &mut self.premium_grain,
+            FeedType::Supplement => &mut self.supplement,
         }
     }
 }
 
-/// [SellNotif]
+/// [FeedPrice]
 /// ------------------------------------------------------------------------------------------
 #[derive(
     Debug,
@@ -273,18 +952,1188 @@ impl Default for BuyNotif {
     Serialize,
     SimpleObject
 )]
-pub struct SellNotif {
+pub struct FeedPrice {
+    pub feed_type: FeedType,
+    pub price: Amount,
+}
+
+/// [FeedingSchedule]
+/// Current feeding status of a cow, with the time each feeding zone starts.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct FeedingSchedule {
     pub cow_name: String,
-    pub is_success: bool,
-    pub failure_reason: String,
+    pub status: FeedingStatus,
+    pub health: CowHealth,
+    pub last_fed_time: Timestamp,
+    pub on_time_at: Timestamp,
+    pub late_at: Timestamp,
+    pub forgot_at: Timestamp,
+    pub death_at: Timestamp,
 }
 
-impl Default for SellNotif {
-    fn default() -> Self {
-        Self {
-            cow_name: String::from(""),
-            is_success: false,
-            failure_reason: String::from(""),
-        }
-    }
-}
\ No newline at end of file
+/// [CowSellQuote]
+/// Breakdown of a cow appraisal price.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct CowSellQuote {
+    pub cow_name: String,
+    pub base_price: Amount,
+    pub on_time_reward: Amount,
+    pub late_reward: Amount,
+    pub forgot_penalty: Amount,
+    pub event_reward: Amount,
+    pub event_penalty: Amount,
+    pub sickness_penalty: Amount,
+    pub is_penalty_capped: bool,
+    pub payout: Amount,
+}
+
+/// [HerdStats]
+/// Aggregate game statistics, recorded by Root chain while handling messages.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct HerdStats {
+    pub total_bought: u64,
+    pub total_sold: u64,
+    pub total_died: u64,
+    pub feeding_stats: FeedingStats,
+    pub treasury_inflow: Amount,
+    pub treasury_outflow: Amount,
+}
+
+/// [BreedPopulation]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct BreedPopulation {
+    pub breed: CowBreed,
+    pub male: u64,
+    pub female: u64,
+}
+
+/// [HerdStatsReport]
+/// HerdStats combined with the living population at the time of the query.
+/// average_feeding_quality is the share of ON TIME feeding, 100% equivalent to 10_000
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct HerdStatsReport {
+    pub living_cows: Vec<BreedPopulation>,
+    pub total_living: u64,
+    pub total_bought: u64,
+    pub total_sold: u64,
+    pub total_died: u64,
+    pub total_removed: u64,
+    pub feeding_stats: FeedingStats,
+    pub average_feeding_quality: u64,
+    pub treasury_inflow: Amount,
+    pub treasury_outflow: Amount,
+    pub active_players: u64,
+}
+
+/// [PlayerData]
+/// Player registry entry, kept by Root chain.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct PlayerData {
+    pub owner: Owner,
+    pub chain_id: ChainId,
+    pub join_time: Timestamp,
+    pub last_activity: Timestamp,
+    pub herd_size: u64,
+}
+
+/// [BreedPrice]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct BreedPrice {
+    pub breed: CowBreed,
+    pub price: Amount,
+}
+
+/// [FeedingConfig]
+/// Feeding limit is in Unix Micros, measured from the cow last fed time.
+/// Feeding reward & penalty is using 2 digit decimal precision, 100% equivalent to 10_000
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub struct FeedingConfig {
+    pub well_fed: u64,
+    pub on_time_feed: u64,
+    pub late_feed: u64,
+    pub starving: u64,
+    pub on_time_reward: u64,
+    pub late_reward: u64,
+    pub forgot_penalty: u64,
+}
+
+scalar!(FeedingConfig);
+
+/// [GameConfig]
+/// Game settings that Root chain admin can change after deployment.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct GameConfig {
+    pub cow_prices: Vec<BreedPrice>,
+    pub feeding_config: FeedingConfig,
+}
+
+/// [PauseState]
+/// While the game is paused, the hunger clock of every cow is frozen at paused_at.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct PauseState {
+    pub is_paused: bool,
+    pub reason: String,
+    pub paused_at: Timestamp,
+}
+
+/// [TreasuryHealth]
+/// Root chain balance against the appraisal price of all living cows.
+/// reserve_ratio, min_reserve_ratio, and payout_scale are using 2 digit decimal precision,
+/// 100% equivalent to 10_000
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct TreasuryHealth {
+    pub balance: Amount,
+    pub total_liability: Amount,
+    pub reserve_ratio: u64,
+    pub min_reserve_ratio: u64,
+    pub is_healthy: bool,
+    pub payout_scale: u64,
+}
+
+/// [LiabilityCache]
+/// Total liability of the root chain, computed once per block at computed_at.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct LiabilityCache {
+    pub computed_at: Timestamp,
+    pub total_liability: Amount,
+}
+
+/// [BreedMarket]
+/// Root chain market data to calculate the dynamic price of a breed.
+/// Recent buys & sells are counted since window_start.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct BreedMarket {
+    pub breed: CowBreed,
+    pub base_price: Amount,
+    pub min_price: Amount,
+    pub max_price: Amount,
+    pub recent_buys: u64,
+    pub recent_sells: u64,
+    pub window_start: Timestamp,
+}
+
+/// [BreedSupply]
+/// Root chain supply limit of a breed.
+/// population_cap limits the living cows, total_supply limits the cows bought since
+/// the limited edition is released. None means unlimited.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct BreedSupply {
+    pub breed: CowBreed,
+    pub population_cap: Option<u64>,
+    pub total_supply: Option<u64>,
+    pub total_minted: u64,
+}
+
+/// [BreedSupplyReport]
+/// BreedSupply combined with the living cows at the time of the query.
+/// remaining is how many cows of the breed can still be bought, None means unlimited.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct BreedSupplyReport {
+    pub breed: CowBreed,
+    pub population_cap: Option<u64>,
+    pub living_cows: u64,
+    pub total_supply: Option<u64>,
+    pub total_minted: u64,
+    pub remaining: Option<u64>,
+}
+
+/// [EventModifier]
+/// Change to the game rules while a seasonal event is active.
+/// multiplier, discount, and scale are using 2 digit decimal precision, 100% equivalent to 10_000
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum EventModifier {
+    OnTimeRewardMultiplier { multiplier: u64 },
+    LateRewardMultiplier { multiplier: u64 },
+    ForgotPenaltyMultiplier { multiplier: u64 },
+    BreedDiscount { breed: CowBreed, discount: u64 },
+    HungerWindowScale { scale: u64 },
+}
+
+scalar!(EventModifier);
+
+/// [SeasonalEvent]
+/// Time-boxed event scheduled by Root chain admin, active from start_time until end_time.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct SeasonalEvent {
+    pub id: u64,
+    pub name: String,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub modifiers: Vec<EventModifier>,
+}
+
+/// [DailyReward]
+/// Daily reward claim record of a player, kept by Root chain and the player's own chain.
+/// streak is the number of consecutive days the reward has been claimed.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct DailyReward {
+    pub owner: Owner,
+    pub streak: u64,
+    pub last_claim_time: Timestamp,
+    pub total_claimed: Amount,
+}
+
+/// [DailyRewardStatus]
+/// Next daily reward of a player at the time of the query.
+/// next_streak and next_reward apply to a claim made at next_claim_time.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct DailyRewardStatus {
+    pub owner: Owner,
+    pub streak: u64,
+    pub is_claimable: bool,
+    pub next_claim_time: Timestamp,
+    pub streak_expire_time: Option<Timestamp>,
+    pub next_streak: u64,
+    pub next_reward: Amount,
+}
+
+/// [Loan]
+/// Tokens borrowed from Root chain treasury, with living cows pledged as collateral.
+/// interest_rate is charged for every started 24 hours,
+/// with 2 digit decimal precision, 100% equivalent to 10_000
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct Loan {
+    pub id: u64,
+    pub owner: Owner,
+    pub cow_names: Vec<String>,
+    pub principal: Amount,
+    pub interest_rate: u64,
+    pub start_time: Timestamp,
+    pub due_time: Timestamp,
+}
+
+/// [LoanReport]
+/// Loan combined with its interest and collateral at the time of the query.
+/// A defaulted loan is overdue or has a dead cow as collateral, and will be seized by Root chain.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct LoanReport {
+    pub loan: Loan,
+    pub interest: Amount,
+    pub amount_due: Amount,
+    pub collateral_value: Amount,
+    pub is_overdue: bool,
+    pub has_dead_collateral: bool,
+    pub is_defaulted: bool,
+}
+
+/// [InsurancePolicy]
+/// Insurance bought for a cow, Root chain pays the coverage if the cow dies before end_time.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct InsurancePolicy {
+    pub id: u64,
+    pub owner: Owner,
+    pub cow_name: String,
+    pub premium: Amount,
+    pub coverage: Amount,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+}
+
+/// [InsurancePolicyReport]
+/// InsurancePolicy combined with the cow feeding status at the time of the query.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct InsurancePolicyReport {
+    pub policy: InsurancePolicy,
+    pub is_active: bool,
+    pub is_claimable: bool,
+}
+
+/// [InsuranceQuote]
+/// Insurance price of a cow, based on its sell value and feeding stats history.
+/// premium_rate is using 2 digit decimal precision, 100% equivalent to 10_000
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct InsuranceQuote {
+    pub cow_name: String,
+    pub coverage: Amount,
+    pub premium_rate: u64,
+    pub premium: Amount,
+    pub duration: u64,
+}
+
+/// [BuyNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct BuyNotif {
+    pub cow_name: String,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [SellNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct SellNotif {
+    pub cow_name: String,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [FeedNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct FeedNotif {
+    pub cow_name: String,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [RewardNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct RewardNotif {
+    pub amount: Amount,
+    pub streak: u64,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [LoanNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct LoanNotif {
+    pub loan_id: u64,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [InsuranceNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct InsuranceNotif {
+    pub cow_name: String,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [ShopNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct ShopNotif {
+    pub feed_type: FeedType,
+    pub quantity: u64,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [FarmStatus]
+/// Farm level of a player, and the barn capacity that caps the herd size.
+/// next_upgrade_cost is empty once the farm reaches the max level.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct FarmStatus {
+    pub owner: Owner,
+    pub level: u64,
+    pub barn_capacity: u64,
+    pub herd_size: u64,
+    pub next_upgrade_cost: Option<Amount>,
+}
+
+/// [FarmNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct FarmNotif {
+    pub level: u64,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [HealthNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct HealthNotif {
+    pub cow_name: String,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [Achievement]
+/// Player milestones watched by Root chain, every achievement is awarded once per player.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum Achievement {
+    #[default]
+    FirstCow,
+    OnTimeFeeder,
+    BreedCollector,
+    ShrewdSeller,
+}
+
+scalar!(Achievement);
+
+/// [PlayerAchievement]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct PlayerAchievement {
+    pub owner: Owner,
+    pub achievement: Achievement,
+    pub bonus: Amount,
+    pub awarded_at: Timestamp,
+}
+
+/// [AchievementRecord]
+/// Achievements of a player, and the progress towards the ones that are counted.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct AchievementRecord {
+    pub achievements: Vec<PlayerAchievement>,
+    pub on_time_feedings: u64,
+}
+
+/// [AchievementNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct AchievementNotif {
+    pub achievement: Achievement,
+    pub bonus: Amount,
+}
+
+/// [QuestObjective]
+/// Goal of a quest, counted from the player actions of the day the quest is active.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum QuestObjective {
+    FeedOnTime { count: u64 },
+    FeedCows { count: u64 },
+    BuyBreed { breed: CowBreed, count: u64 },
+    SellCows { count: u64 },
+}
+
+scalar!(QuestObjective);
+
+impl QuestObjective {
+    pub fn count(&self) -> u64 {
+        match *self {
+            QuestObjective::FeedOnTime { count } => count,
+            QuestObjective::FeedCows { count } => count,
+            QuestObjective::BuyBreed { count, .. } => count,
+            QuestObjective::SellCows { count } => count,
+        }
+    }
+}
+
+/// [Quest]
+/// Quest definition set by Root chain admin.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub struct Quest {
+    pub name: String,
+    pub objective: QuestObjective,
+    pub reward: Amount,
+}
+
+scalar!(Quest);
+
+/// [QuestSchedule]
+/// Quest pool and its rotation, every day starting from start_time gets the next quests_per_day quests of the pool.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct QuestSchedule {
+    pub id: u64,
+    pub quests: Vec<Quest>,
+    pub quests_per_day: u64,
+    pub start_time: Timestamp,
+}
+
+/// [QuestProgress]
+/// Progress of a player on a quest of the day, quest_index points to the quest in the schedule pool.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct QuestProgress {
+    pub schedule_id: u64,
+    pub day: u64,
+    pub quest_index: u64,
+    pub progress: u64,
+    pub is_claimed: bool,
+}
+
+/// [DailyQuest]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct DailyQuest {
+    pub quest_index: u64,
+    pub quest: Quest,
+    pub progress: u64,
+    pub is_completed: bool,
+    pub is_claimed: bool,
+    pub ends_at: Timestamp,
+}
+
+/// [QuestNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct QuestNotif {
+    pub quest_index: u64,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [RenameNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct RenameNotif {
+    pub cow_name: String,
+    pub new_name: String,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [CoopRule]
+/// Who may act on the cooperative herd, FounderOnly leaves it to the founder.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum CoopRule {
+    #[default]
+    AnyMember,
+    FounderOnly,
+}
+
+scalar!(CoopRule);
+
+/// [CoopAction]
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum CoopAction {
+    #[default]
+    Create,
+    Deposit,
+    BuyCow,
+    FeedCow,
+    SellCow,
+    Dissolve,
+}
+
+scalar!(CoopAction);
+
+/// [CoopMember]
+/// The member share of the sale proceeds follows the member share of all deposits.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct CoopMember {
+    pub owner: Owner,
+    pub deposited: Amount,
+}
+
+/// [Cooperative]
+/// Farm run together by several players, kept by Root chain.
+/// herd_owner is the owner of every cooperative cow, no player chain can sign for it.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct Cooperative {
+    pub id: u64,
+    pub name: String,
+    pub founder: Owner,
+    pub herd_owner: Owner,
+    pub members: Vec<CoopMember>,
+    pub treasury: Amount,
+    pub feed_rule: CoopRule,
+    pub trade_rule: CoopRule,
+    pub created_at: Timestamp,
+}
+
+/// [CoopNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct CoopNotif {
+    pub coop_id: u64,
+    pub action: CoopAction,
+    pub cow_name: String,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [CaretakerGrant]
+/// Permission from the owner for the caretaker to feed the listed cows, or every cow when the list is empty.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct CaretakerGrant {
+    pub owner: Owner,
+    pub caretaker: Owner,
+    pub cow_names: Vec<String>,
+    pub granted_at: Timestamp,
+    pub expires_at: Timestamp,
+    pub feed_count: u64,
+    pub last_fed_at: Option<Timestamp>,
+}
+
+/// [CaretakerAction]
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum CaretakerAction {
+    #[default]
+    Grant,
+    Revoke,
+    Feed,
+}
+
+scalar!(CaretakerAction);
+
+/// [CaretakerNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct CaretakerNotif {
+    pub owner: Owner,
+    pub caretaker: Owner,
+    pub action: CaretakerAction,
+    pub cow_name: String,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
+
+/// [KeeperBudget]
+/// Feeding budget prepaid by the owner, any keeper may feed the cow ON TIME from it.
+/// Every feeding costs the feed price of feed_type, plus the keeper fee.
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct KeeperBudget {
+    pub cow_name: String,
+    pub owner: Owner,
+    pub feed_type: FeedType,
+    pub balance: Amount,
+    pub feed_count: u64,
+    pub last_keeper: Option<Owner>,
+    pub last_fed_at: Option<Timestamp>,
+}
+
+/// [KeeperAction]
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+pub enum KeeperAction {
+    #[default]
+    Fund,
+    Withdraw,
+    Feed,
+    Fee,
+}
+
+scalar!(KeeperAction);
+
+/// [KeeperNotif]
+/// ------------------------------------------------------------------------------------------
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Serialize,
+    SimpleObject
+)]
+pub struct KeeperNotif {
+    pub cow_name: String,
+    pub action: KeeperAction,
+    pub amount: Amount,
+    pub is_success: bool,
+    pub failure_reason: String,
+}
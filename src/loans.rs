@@ -0,0 +1,274 @@
+use linera_sdk::base::{Amount, Owner, Timestamp};
+use crate::{CowData, FeedingStatus, Loan, LoanReport};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Loans]
+/// Tokens borrowed from Root chain treasury, with living cows pledged as collateral.
+/// Pledged cows can't be sold until the loan is repaid.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub async fn get_loan(&self, loan_id: u64) -> Option<Loan> {
+        self.loans.get(&loan_id).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Loan Result");
+            })
+    }
+
+    pub async fn get_loans(&self, owner: Option<Owner>) -> Vec<Loan> {
+        let mut loans = Vec::new();
+        self.loans
+            .for_each_index_value(|_, loan| {
+                if owner.is_none() || owner == Some(loan.owner) {
+                    loans.push(loan);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read loans");
+            });
+        loans
+    }
+
+    pub async fn get_cow_loan(&self, cow_name: &String) -> Option<Loan> {
+        let loan_id = self.cow_loans.get(cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow Loan Result");
+            })?;
+        self.get_loan(loan_id).await
+    }
+
+    pub async fn is_cow_pledged(&self, cow_name: &String) -> bool {
+        self.cow_loans.contains_key(cow_name).await.unwrap_or(false)
+    }
+
+    pub async fn check_loan_collateral(
+        &self,
+        owner: Owner,
+        cow_names: &[String],
+        system_time: Timestamp,
+    ) -> Result<Vec<CowData>, String> {
+        if cow_names.is_empty() {
+            return Err(String::from("Loan must have at least 1 cow as collateral"));
+        }
+
+        // every cow must be alive, owned by the borrower, and not pledged yet
+        let mut cows: Vec<CowData> = Vec::new();
+        for cow_name in cow_names.iter() {
+            if cows.iter().any(|cow| cow.name.eq(cow_name)) {
+                return Err(format!("{:?} is pledged twice", cow_name));
+            }
+            let cow = self.cow_db.get(cow_name).await
+                .unwrap_or_else(|_| {
+                    panic!("unable to get Cow DB Result");
+                })
+                .ok_or_else(|| format!("{:?} is not exist", cow_name))?;
+            if cow.owner.ne(&owner) {
+                return Err(format!("{:?} is not yours to pledge", cow_name));
+            }
+            if self.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
+                return Err(format!("{:?} has died", cow_name));
+            }
+            if self.is_cow_pledged(cow_name).await {
+                return Err(format!("{:?} is already pledged", cow_name));
+            }
+            cows.push(cow);
+        }
+        Ok(cows)
+    }
+
+    pub async fn get_max_loan(&self, cows: Vec<CowData>, system_time: Timestamp) -> Amount {
+        let mut collateral_value = Amount::ZERO;
+        for cow in cows.into_iter() {
+            collateral_value.saturating_add_assign(self.cow_sell_value(cow, system_time).await);
+        }
+        self.get_price_share(collateral_value, LOAN_TO_VALUE)
+    }
+
+    pub fn get_loan_interest(&self, loan: &Loan, system_time: Timestamp) -> Amount {
+        // interest is charged for every started 24 hours, nothing is charged at the start time
+        let elapsed = system_time.micros().saturating_sub(loan.start_time.micros());
+        let days = elapsed.div_ceil(UNIX_MICROS_IN_24_HOURS);
+        let interest_share = (loan.interest_rate as u128) * (days as u128);
+        self.get_price_share(loan.principal, interest_share)
+    }
+
+    pub async fn get_loan_report(&self, loan: Loan, system_time: Timestamp) -> LoanReport {
+        let interest = self.get_loan_interest(&loan, system_time);
+
+        // check every pledged cow, cows that are no longer in DB count as dead
+        let mut collateral_value = Amount::ZERO;
+        let mut has_dead_collateral = false;
+        for cow_name in loan.cow_names.iter() {
+            let cow = self.cow_db.get(cow_name).await
+                .unwrap_or_else(|_| {
+                    panic!("unable to get Cow DB Result");
+                });
+            match cow {
+                Some(cow) if self.get_cow_status(&cow, system_time) != FeedingStatus::Dead => {
+                    collateral_value.saturating_add_assign(self.cow_sell_value(cow, system_time).await);
+                }
+                _ => has_dead_collateral = true,
+            }
+        }
+
+        let is_overdue = system_time > loan.due_time;
+        LoanReport {
+            amount_due: loan.principal.saturating_add(interest),
+            loan,
+            interest,
+            collateral_value,
+            is_overdue,
+            has_dead_collateral,
+            is_defaulted: is_overdue || has_dead_collateral,
+        }
+    }
+
+    pub async fn open_loan(
+        &mut self,
+        owner: Owner,
+        cow_names: Vec<String>,
+        principal: Amount,
+        system_time: Timestamp,
+    ) -> Loan {
+        let loan_id = *self.next_loan_id.get() + 1;
+        self.next_loan_id.set(loan_id);
+        let loan = Loan {
+            id: loan_id,
+            owner,
+            cow_names,
+            principal,
+            interest_rate: LOAN_INTEREST_RATE,
+            start_time: system_time,
+            due_time: system_time.saturating_add_micros(LOAN_DURATION),
+        };
+        self.save_loan(loan.clone());
+        loan
+    }
+
+    pub fn save_loan(&mut self, loan: Loan) {
+        for cow_name in loan.cow_names.iter() {
+            self.cow_loans.insert(cow_name, loan.id).unwrap_or_else(|_| {
+                panic!("Failed to pledge {:?}", cow_name);
+            });
+        }
+        self.loans.insert(&loan.id.clone(), loan).unwrap_or_else(|_| {
+            panic!("Failed to update Loan");
+        });
+    }
+
+    pub async fn resume_loans(&mut self, paused_at: Timestamp, paused_duration: u64) {
+        // loan term moves forward, so the paused duration is neither overdue nor charged
+        // loan that was already overdue before the game paused stays overdue
+        for mut loan in self.get_loans(None).await.into_iter() {
+            if loan.due_time <= paused_at || loan.start_time > paused_at {
+                continue;
+            }
+            loan.start_time = loan.start_time.saturating_add_micros(paused_duration);
+            loan.due_time = loan.due_time.saturating_add_micros(paused_duration);
+            self.save_loan(loan);
+        }
+    }
+
+    pub fn close_loan(&mut self, loan: &Loan) {
+        for cow_name in loan.cow_names.iter() {
+            self.cow_loans.remove(cow_name).unwrap_or_else(|_| {
+                panic!("Failed to release {:?}", cow_name);
+            });
+        }
+        self.loans.remove(&loan.id).unwrap_or_else(|_| {
+            panic!("Failed to remove Loan");
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::constants::*;
+    use crate::test_utils::{load_state, test_cow, test_owner};
+
+    fn test_cow_names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| String::from(*name)).collect()
+    }
+
+    #[test]
+    fn interest_is_charged_for_every_started_day() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let start_time = Timestamp::from(1_000);
+            let loan = state.open_loan(test_owner("01"), test_cow_names(&["Bessie"]), Amount::from_tokens(1000), start_time).await;
+
+            let interest_at = |micros: u64| state.get_loan_interest(&loan, start_time.saturating_add_micros(micros));
+            assert_eq!(interest_at(0), Amount::ZERO);
+            assert_eq!(interest_at(1), Amount::from_tokens(10));
+            assert_eq!(interest_at(UNIX_MICROS_IN_24_HOURS), Amount::from_tokens(10));
+            assert_eq!(interest_at(UNIX_MICROS_IN_24_HOURS + 1), Amount::from_tokens(20));
+            assert_eq!(interest_at(LOAN_DURATION), Amount::from_tokens(70));
+        });
+    }
+
+    #[test]
+    fn loan_defaults_when_overdue_or_collateral_dies() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let start_time = Timestamp::from(UNIX_MICROS_IN_24_HOURS);
+            state.create_new_or_update_cow_data(String::from("Bessie"), test_cow("Bessie", test_owner("01"), UNIX_MICROS_IN_24_HOURS)).await;
+            let loan = state.open_loan(test_owner("01"), test_cow_names(&["Bessie"]), Amount::from_tokens(100), start_time).await;
+
+            let report = state.get_loan_report(loan.clone(), start_time).await;
+            assert!(!report.is_defaulted);
+            assert_eq!(report.amount_due, Amount::from_tokens(100));
+            assert!(report.collateral_value > Amount::ZERO);
+
+            // the cow starves before the loan is due
+            let starved_at = start_time.saturating_add_micros(UNIX_MICROS_IN_24_HOURS + 1);
+            let report = state.get_loan_report(loan.clone(), starved_at).await;
+            assert!(report.has_dead_collateral);
+            assert!(!report.is_overdue);
+            assert!(report.is_defaulted);
+
+            // the cow is fed, but the loan is past due
+            let due_at = loan.due_time.saturating_add_micros(1);
+            state.create_new_or_update_cow_data(String::from("Bessie"), test_cow("Bessie", test_owner("01"), due_at.micros())).await;
+            let report = state.get_loan_report(loan, due_at).await;
+            assert!(!report.has_dead_collateral);
+            assert!(report.is_overdue);
+            assert!(report.is_defaulted);
+        });
+    }
+
+    #[test]
+    fn collateral_must_be_living_unpledged_cows_of_the_borrower() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let system_time = Timestamp::from(UNIX_MICROS_IN_24_HOURS + 1);
+            state.create_new_or_update_cow_data(String::from("Bessie"), test_cow("Bessie", test_owner("01"), UNIX_MICROS_IN_24_HOURS)).await;
+            state.create_new_or_update_cow_data(String::from("Daisy"), test_cow("Daisy", test_owner("02"), UNIX_MICROS_IN_24_HOURS)).await;
+            state.create_new_or_update_cow_data(String::from("Molly"), test_cow("Molly", test_owner("01"), 0)).await;
+
+            let check = |names: &[&str]| test_cow_names(names);
+            let owner = test_owner("01");
+            assert!(state.check_loan_collateral(owner, &check(&[]), system_time).await.is_err());
+            assert!(state.check_loan_collateral(owner, &check(&["Bessie"]), system_time).await.is_ok());
+            assert!(state.check_loan_collateral(owner, &check(&["Bessie", "Bessie"]), system_time).await.is_err());
+            assert!(state.check_loan_collateral(owner, &check(&["Daisy"]), system_time).await.is_err());
+            assert!(state.check_loan_collateral(owner, &check(&["Molly"]), system_time).await.is_err());
+            assert!(state.check_loan_collateral(owner, &check(&["Lulu"]), system_time).await.is_err());
+
+            // pledged cow is released once the loan is closed
+            let loan = state.open_loan(test_owner("01"), test_cow_names(&["Bessie"]), Amount::from_tokens(100), system_time).await;
+            assert!(state.is_cow_pledged(&String::from("Bessie")).await);
+            assert!(state.check_loan_collateral(owner, &check(&["Bessie"]), system_time).await.is_err());
+            state.close_loan(&loan);
+            assert!(!state.is_cow_pledged(&String::from("Bessie")).await);
+            assert_eq!(state.get_loan(loan.id).await, None);
+        });
+    }
+}
@@ -0,0 +1,352 @@
+use linera_sdk::base::{Owner, Timestamp};
+use linera_sdk::views::{MapView, QueueView, View};
+use crate::{BuyNotif, CowBreed, CowData, CowGender, CowHealth, FeedingStats};
+use serde::{Deserialize, Serialize};
+use crate::state::MicroCow;
+
+/// [Storage Version]
+/// Version of the stored state layout, bump it whenever a stored type changes its fields,
+/// and add a migration step from the previous version.
+/// 0 = original layout, without version
+/// 1 = BuyNotif with failure reason
+/// 2 = CowData with health
+/// 3 = CowData with event bonus
+/// ------------------------------------------------------------------------------------------
+pub const STORAGE_VERSION: u32 = 3;
+
+/// [Legacy Records]
+/// Stored records of previous versions, kept only to be read by the migration.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BuyNotifV0 {
+    pub cow_name: String,
+    pub is_success: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CowDataV1 {
+    pub id: String,
+    pub name: String,
+    pub breed: CowBreed,
+    pub gender: CowGender,
+    pub born_time: Timestamp,
+    pub last_fed_time: Timestamp,
+    pub feeding_stats: FeedingStats,
+    pub owner: Owner,
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CowDataV2 {
+    pub id: String,
+    pub name: String,
+    pub breed: CowBreed,
+    pub gender: CowGender,
+    pub born_time: Timestamp,
+    pub last_fed_time: Timestamp,
+    pub feeding_stats: FeedingStats,
+    pub owner: Owner,
+    pub health: CowHealth,
+}
+
+/// [Migration]
+/// Upgrade stored records to the current layout, it runs on every load
+/// and does nothing once the state has reached the current version.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_storage_version(&self) -> u32 {
+        *self.storage_version.get()
+    }
+
+    pub async fn migrate(&mut self) {
+        let storage_version = self.get_storage_version();
+        if storage_version > STORAGE_VERSION {
+            panic!("Stored state version {} is newer than {}", storage_version, STORAGE_VERSION);
+        }
+        if storage_version < 1 {
+            self.migrate_to_v1().await;
+        }
+        // v2 step already rewrites cows in the current layout
+        if storage_version < 2 {
+            self.migrate_to_v2().await;
+        } else if storage_version < 3 {
+            self.migrate_to_v3().await;
+        }
+        self.storage_version.set(STORAGE_VERSION);
+    }
+
+    async fn migrate_to_v1(&mut self) {
+        // read buy notifications with the legacy layout
+        let legacy: QueueView<BuyNotifV0> = QueueView::load(self.buy_notification.context().clone())
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to load legacy Buy notifications");
+            });
+        let legacy_notifications = legacy
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read legacy Buy notifications");
+            });
+
+        // rewrite them with an empty failure reason
+        self.buy_notification.clear();
+        for notification in legacy_notifications.into_iter() {
+            self.buy_notification.push_back(BuyNotif {
+                cow_name: notification.cow_name,
+                is_success: notification.is_success,
+                failure_reason: String::from(""),
+            });
+        }
+    }
+
+    async fn migrate_to_v2(&mut self) {
+        // read cows with the legacy layout
+        let legacy: MapView<String, CowDataV1> = MapView::load(self.cow_db.context().clone())
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to load legacy Cow DB");
+            });
+        let mut legacy_cows = Vec::new();
+        legacy
+            .for_each_index_value(|_, cow| {
+                legacy_cows.push(cow);
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read legacy Cow DB");
+            });
+
+        // rewrite them as healthy cows
+        for cow in legacy_cows.into_iter() {
+            let cow_name = cow.name.clone();
+            let cow_data = CowData {
+                id: cow.id,
+                name: cow.name,
+                breed: cow.breed,
+                gender: cow.gender,
+                born_time: cow.born_time,
+                last_fed_time: cow.last_fed_time,
+                feeding_stats: cow.feeding_stats,
+                owner: cow.owner,
+                health: CowHealth::Healthy,
+                event_bonus: 0,
+            };
+            self.cow_db.insert(&cow_name, cow_data).unwrap_or_else(|_| {
+                panic!("Failed to migrate {:?}", cow_name);
+            });
+        }
+    }
+
+    async fn migrate_to_v3(&mut self) {
+        // read cows with the legacy layout
+        let legacy: MapView<String, CowDataV2> = MapView::load(self.cow_db.context().clone())
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to load legacy Cow DB");
+            });
+        let mut legacy_cows = Vec::new();
+        legacy
+            .for_each_index_value(|_, cow| {
+                legacy_cows.push(cow);
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read legacy Cow DB");
+            });
+
+        // rewrite them without any event bonus, their feedings are appraised with the base config
+        for cow in legacy_cows.into_iter() {
+            let cow_name = cow.name.clone();
+            let cow_data = CowData {
+                id: cow.id,
+                name: cow.name,
+                breed: cow.breed,
+                gender: cow.gender,
+                born_time: cow.born_time,
+                last_fed_time: cow.last_fed_time,
+                feeding_stats: cow.feeding_stats,
+                owner: cow.owner,
+                health: cow.health,
+                event_bonus: 0,
+            };
+            self.cow_db.insert(&cow_name, cow_data).unwrap_or_else(|_| {
+                panic!("Failed to migrate {:?}", cow_name);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, ChainId, Owner, Timestamp};
+    use linera_sdk::views::{linera_views, KeyValueStore, MapView, QueueView, RegisterView, RootView, View, ViewStorageContext};
+    use crate::{AccountData, BreedMarket, BreedSupply, BuyNotif, CowBreed, CowData, CowGender, CowHealth, FeedNotif, FeedingStats, GameConfig, HerdStats, PauseState, PlayerData, SellNotif};
+    use crate::state::MicroCow;
+    use crate::test_utils::{load_state, test_cow, test_owner};
+    use super::{BuyNotifV0, CowDataV1, CowDataV2, STORAGE_VERSION};
+
+    /// Stored state layout of version 0, with the same fields order as MicroCow.
+    #[derive(RootView)]
+    #[view(context = "ViewStorageContext")]
+    struct MicroCowV0 {
+        app_data: RegisterView<AccountData>,
+        cow_db: MapView<String, CowDataV1>,
+        cow_ownership: MapView<String, u8>,
+        buy_notification: QueueView<BuyNotifV0>,
+    }
+
+    /// Stored state layout of version 2, with the same fields order as MicroCow, up to the storage version.
+    #[derive(RootView)]
+    #[view(context = "ViewStorageContext")]
+    struct MicroCowV2 {
+        app_data: RegisterView<AccountData>,
+        cow_db: MapView<String, CowDataV2>,
+        cow_ownership: MapView<String, u8>,
+        buy_notification: QueueView<BuyNotif>,
+        sell_notification: QueueView<SellNotif>,
+        feed_notification: QueueView<FeedNotif>,
+        herd_stats: RegisterView<HerdStats>,
+        players: MapView<Owner, PlayerData>,
+        player_chains: MapView<ChainId, Owner>,
+        game_config: RegisterView<Option<GameConfig>>,
+        admins: RegisterView<Vec<Owner>>,
+        pause_state: RegisterView<PauseState>,
+        min_reserve_ratio: RegisterView<Option<u64>>,
+        breed_markets: RegisterView<Vec<BreedMarket>>,
+        breed_supplies: RegisterView<Vec<BreedSupply>>,
+        storage_version: RegisterView<u32>,
+    }
+
+    fn test_account() -> AccountData {
+        AccountData::new(test_owner("01"), Amount::from_tokens(10000), ChainId::root(0), false)
+    }
+
+    fn stored_cow(name: &str) -> CowData {
+        let mut cow = test_cow(name, test_owner("01"), 2_000);
+        cow.born_time = Timestamp::from(1_000);
+        cow.feeding_stats = FeedingStats { on_time: 3, late: 2, forgot: 1 };
+        cow
+    }
+
+    fn test_legacy_cow(name: &str) -> CowDataV1 {
+        CowDataV1 {
+            id: String::from("cow-id"),
+            name: String::from(name),
+            breed: CowBreed::Jersey,
+            gender: CowGender::Female,
+            born_time: Timestamp::from(1_000),
+            last_fed_time: Timestamp::from(2_000),
+            feeding_stats: FeedingStats { on_time: 3, late: 2, forgot: 1 },
+            owner: test_owner("01"),
+        }
+    }
+
+    async fn write_legacy_state(store: &KeyValueStore) {
+        let mut legacy = MicroCowV0::load(ViewStorageContext::from(store.to_mut())).await.unwrap();
+        legacy.app_data.set(test_account());
+        legacy.cow_db.insert("Bessie", test_legacy_cow("Bessie")).unwrap();
+        legacy.cow_ownership.insert("Bessie", 1).unwrap();
+        legacy.buy_notification.push_back(BuyNotifV0 { cow_name: String::from("Bessie"), is_success: true });
+        legacy.buy_notification.push_back(BuyNotifV0 { cow_name: String::from("Daisy"), is_success: false });
+        legacy.save().await.unwrap();
+    }
+
+    async fn write_v2_state(store: &KeyValueStore) {
+        let mut legacy = MicroCowV2::load(ViewStorageContext::from(store.to_mut())).await.unwrap();
+        legacy.app_data.set(test_account());
+        let cow = stored_cow("Bessie");
+        legacy.cow_db.insert("Bessie", CowDataV2 {
+            id: cow.id,
+            name: cow.name,
+            breed: cow.breed,
+            gender: cow.gender,
+            born_time: cow.born_time,
+            last_fed_time: cow.last_fed_time,
+            feeding_stats: cow.feeding_stats,
+            owner: cow.owner,
+            health: CowHealth::Sick { sick_since: Timestamp::from(3_000) },
+        }).unwrap();
+        legacy.storage_version.set(2);
+        legacy.save().await.unwrap();
+    }
+
+    async fn load_migrated_state(store: &KeyValueStore) -> MicroCow {
+        let mut state = load_state(store).await;
+        state.migrate().await;
+        state
+    }
+
+    #[test]
+    fn legacy_state_is_migrated_on_load() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            write_legacy_state(&store).await;
+
+            let state = load_migrated_state(&store).await;
+            assert_eq!(state.get_storage_version(), STORAGE_VERSION);
+            assert_eq!(*state.app_data.get(), test_account());
+            assert_eq!(state.cow_db.get("Bessie").await.unwrap(), Some(stored_cow("Bessie")));
+            assert_eq!(state.cow_db.get("Bessie").await.unwrap().unwrap().health, CowHealth::Healthy);
+            assert_eq!(state.cow_ownership.get("Bessie").await.unwrap(), Some(1));
+            assert_eq!(
+                state.buy_notification.elements().await.unwrap(),
+                vec![
+                    BuyNotif { cow_name: String::from("Bessie"), is_success: true, failure_reason: String::from("") },
+                    BuyNotif { cow_name: String::from("Daisy"), is_success: false, failure_reason: String::from("") },
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn migrated_state_is_saved_and_reloaded() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            write_legacy_state(&store).await;
+
+            let mut state = load_migrated_state(&store).await;
+            state.buy_notification.push_back(BuyNotif {
+                cow_name: String::from("Molly"),
+                is_success: false,
+                failure_reason: String::from("Cow name is taken"),
+            });
+            state.save().await.unwrap();
+
+            // current layout must not be migrated again
+            let state = load_migrated_state(&store).await;
+            assert_eq!(state.get_storage_version(), STORAGE_VERSION);
+            let notifications = state.buy_notification.elements().await.unwrap();
+            assert_eq!(notifications.len(), 3);
+            assert_eq!(notifications[2].failure_reason, "Cow name is taken");
+            assert_eq!(state.cow_db.get("Bessie").await.unwrap(), Some(stored_cow("Bessie")));
+        });
+    }
+
+    #[test]
+    fn v2_cows_are_migrated_without_event_bonus() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            write_v2_state(&store).await;
+
+            let state = load_migrated_state(&store).await;
+            assert_eq!(state.get_storage_version(), STORAGE_VERSION);
+            let cow = state.cow_db.get("Bessie").await.unwrap().unwrap();
+            assert_eq!(cow.health, CowHealth::Sick { sick_since: Timestamp::from(3_000) });
+            assert_eq!(cow.feeding_stats, FeedingStats { on_time: 3, late: 2, forgot: 1 });
+            assert_eq!(cow.event_bonus, 0);
+        });
+    }
+
+    #[test]
+    fn empty_state_starts_at_current_version() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = load_migrated_state(&store).await;
+            assert_eq!(state.get_storage_version(), STORAGE_VERSION);
+            assert_eq!(state.buy_notification.count(), 0);
+        });
+    }
+}
@@ -0,0 +1,251 @@
+use linera_sdk::base::{Amount, Timestamp};
+use crate::{BreedMarket, CowBreed, FeedingStatus};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Pricing]
+/// Dynamic cow price based on breed living population, and its recent buys & sells.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_breed_markets(&self) -> Vec<BreedMarket> {
+        let breed_markets = self.breed_markets.get().clone();
+        if !breed_markets.is_empty() {
+            return breed_markets;
+        }
+
+        // use the current cow price as base price, until the market is recorded
+        CowBreed::ALL
+            .iter()
+            .map(|breed| {
+                let base_price = self.get_cow_price(*breed);
+                BreedMarket {
+                    breed: *breed,
+                    base_price,
+                    min_price: self.get_price_share(base_price, PRICE_MIN_BOUND),
+                    max_price: self.get_price_share(base_price, PRICE_MAX_BOUND),
+                    recent_buys: 0,
+                    recent_sells: 0,
+                    window_start: Timestamp::from(0),
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_breed_market(&self, breed: CowBreed) -> BreedMarket {
+        self.get_breed_markets()
+            .into_iter()
+            .find(|market| market.breed == breed)
+            .unwrap_or_else(|| {
+                panic!("unable to get {:?} market", breed);
+            })
+    }
+
+    pub fn set_base_price(&mut self, breed: CowBreed, base_price: Amount) {
+        // bounds follow the base price with the same proportion, so the new price isn't clamped to the old bounds
+        let market = self.get_breed_market(breed);
+        let (min_share, max_share) = if market.base_price == Amount::ZERO {
+            (PRICE_MIN_BOUND, PRICE_MAX_BOUND)
+        } else {
+            (
+                market.min_price.saturating_mul(PRECISION_100_PERCENT as u128).saturating_div(market.base_price),
+                market.max_price.saturating_mul(PRECISION_100_PERCENT as u128).saturating_div(market.base_price),
+            )
+        };
+        let min_price = self.get_price_share(base_price, min_share);
+        let max_price = self.get_price_share(base_price, max_share);
+        self.update_breed_market(breed, None, |market| {
+            market.base_price = base_price;
+            market.min_price = min_price;
+            market.max_price = max_price;
+        });
+    }
+
+    pub fn set_price_bounds(&mut self, breed: CowBreed, min_price: Amount, max_price: Amount) {
+        if min_price > max_price {
+            panic!("minimum price must not exceed maximum price");
+        }
+        self.update_breed_market(breed, None, |market| {
+            market.min_price = min_price;
+            market.max_price = max_price;
+        });
+    }
+
+    pub fn record_market_buy(&mut self, breed: CowBreed, system_time: Timestamp) {
+        self.update_breed_market(breed, Some(system_time), |market| {
+            market.recent_buys += 1;
+        });
+    }
+
+    pub fn record_market_sell(&mut self, breed: CowBreed, system_time: Timestamp) {
+        self.update_breed_market(breed, Some(system_time), |market| {
+            market.recent_sells += 1;
+        });
+    }
+
+    pub async fn reprice_breed(&mut self, breed: CowBreed, system_time: Timestamp) -> bool {
+        // return true if the breed price has changed
+        // recent buys & sells outside the market window are no longer counted
+        self.update_breed_market(breed, Some(system_time), |_| {});
+        let market = self.get_breed_market(breed);
+        let living_cows = self.count_living_cows(breed, system_time).await;
+
+        // calculate price multiplier from demand & supply
+        let demand = (market.recent_buys as i128) - (market.recent_sells as i128);
+        let multiplier = PRECISION_100_PERCENT
+            + demand * PRICE_DEMAND_STEP
+            - (living_cows as i128) * PRICE_SUPPLY_STEP;
+        let multiplier = multiplier.max(0) as u128;
+
+        // price must stay inside the admin bounds
+        let price = self.get_price_share(market.base_price, multiplier)
+            .max(market.min_price)
+            .min(market.max_price);
+        if price == self.get_cow_price(breed) {
+            return false;
+        }
+        self.set_cow_price(breed, price);
+        true
+    }
+
+    pub async fn count_living_cows(&self, breed: CowBreed, system_time: Timestamp) -> u64 {
+        let mut living_cows = 0;
+        self.cow_db
+            .for_each_index_value(|_, cow| {
+                let is_alive = self.get_cow_status(&cow, system_time) != FeedingStatus::Dead;
+                if cow.breed == breed && is_alive {
+                    living_cows += 1;
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cow db");
+            });
+        living_cows
+    }
+
+    fn update_breed_market<F>(&mut self, breed: CowBreed, system_time: Option<Timestamp>, update: F)
+    where
+        F: FnOnce(&mut BreedMarket),
+    {
+        let mut breed_markets = self.get_breed_markets();
+        if let Some(market) = breed_markets.iter_mut().find(|market| market.breed == breed) {
+            // start a new market window when the current one has passed
+            if let Some(system_time) = system_time {
+                let window_end = market.window_start.saturating_add_micros(PRICE_MARKET_WINDOW);
+                if system_time > window_end {
+                    market.recent_buys = 0;
+                    market.recent_sells = 0;
+                    market.window_start = system_time;
+                }
+            }
+            update(market);
+        }
+        self.breed_markets.set(breed_markets);
+    }
+
+    pub fn get_price_share(&self, price: Amount, share: u128) -> Amount {
+        // share is using 2 digit decimal precision, where 100% equivalent to 10_000
+        let amount = price.saturating_mul(share);
+        Amount::from_tokens(amount.saturating_div(Amount::from_tokens(PRECISION_100_PERCENT as u128)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::CowBreed;
+    use crate::test_utils::{load_state, test_cow, test_owner};
+
+    #[test]
+    fn default_bounds_follow_base_price() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = load_state(&store).await;
+            let market = state.get_breed_market(CowBreed::Jersey);
+            assert_eq!(market.base_price, Amount::from_tokens(1000));
+            assert_eq!((market.min_price, market.max_price), (Amount::from_tokens(500), Amount::from_tokens(2000)));
+        });
+    }
+
+    #[test]
+    fn base_price_change_rescales_bounds() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            state.set_base_price(CowBreed::Jersey, Amount::from_tokens(5000));
+            let market = state.get_breed_market(CowBreed::Jersey);
+            assert_eq!((market.min_price, market.max_price), (Amount::from_tokens(2500), Amount::from_tokens(10000)));
+
+            // admin bounds keep their proportion to the base price
+            state.set_price_bounds(CowBreed::Jersey, Amount::from_tokens(4000), Amount::from_tokens(7500));
+            state.set_base_price(CowBreed::Jersey, Amount::from_tokens(2000));
+            let market = state.get_breed_market(CowBreed::Jersey);
+            assert_eq!((market.min_price, market.max_price), (Amount::from_tokens(1600), Amount::from_tokens(3000)));
+        });
+    }
+
+    #[test]
+    fn reprice_is_not_clamped_to_old_bounds() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            state.set_base_price(CowBreed::Jersey, Amount::from_tokens(5000));
+            assert!(state.reprice_breed(CowBreed::Jersey, Timestamp::from(1_000)).await);
+            assert_eq!(state.get_cow_price(CowBreed::Jersey), Amount::from_tokens(5000));
+
+            state.set_base_price(CowBreed::Jersey, Amount::from_tokens(100));
+            assert!(state.reprice_breed(CowBreed::Jersey, Timestamp::from(2_000)).await);
+            assert_eq!(state.get_cow_price(CowBreed::Jersey), Amount::from_tokens(100));
+        });
+    }
+
+    #[test]
+    fn demand_moves_price_inside_bounds() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let system_time = Timestamp::from(1_000);
+            for _ in 0..3 {
+                state.record_market_buy(CowBreed::Jersey, system_time);
+            }
+            assert!(state.reprice_breed(CowBreed::Jersey, system_time).await);
+            assert_eq!(state.get_cow_price(CowBreed::Jersey), Amount::from_tokens(1030));
+
+            // price stops at the maximum bound
+            for _ in 0..200 {
+                state.record_market_buy(CowBreed::Jersey, system_time);
+            }
+            assert!(state.reprice_breed(CowBreed::Jersey, system_time).await);
+            assert_eq!(state.get_cow_price(CowBreed::Jersey), Amount::from_tokens(2000));
+            assert!(!state.reprice_breed(CowBreed::Jersey, system_time).await);
+        });
+    }
+
+    #[test]
+    fn living_population_lowers_price() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let system_time = Timestamp::from(1_000);
+            for name in ["Bessie", "Daisy", "Clover", "Molly", "Rosie"] {
+                state.create_new_or_update_cow_data(String::from(name), test_cow(name, test_owner("01"), 1_000)).await;
+            }
+            assert!(state.reprice_breed(CowBreed::Jersey, system_time).await);
+            assert_eq!(state.get_cow_price(CowBreed::Jersey), Amount::from_tokens(995));
+            assert_eq!(state.get_cow_price(CowBreed::Hereford), Amount::from_tokens(5000));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "minimum price must not exceed maximum price")]
+    fn price_bounds_must_be_in_order() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            state.set_price_bounds(CowBreed::Jersey, Amount::from_tokens(2000), Amount::from_tokens(1000));
+        });
+    }
+}
@@ -0,0 +1,262 @@
+use linera_sdk::base::{Owner, Timestamp};
+use crate::{CowBreed, DailyQuest, Quest, QuestObjective, QuestProgress, QuestSchedule};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [QuestAction]
+/// Player action that counts towards the quests of the day.
+/// ------------------------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum QuestAction {
+    FeedOnTime,
+    FeedCow,
+    BuyCow(CowBreed),
+    SellCow,
+}
+
+impl QuestAction {
+    fn is_counted_by(&self, objective: &QuestObjective) -> bool {
+        match (*self, *objective) {
+            (QuestAction::FeedOnTime, QuestObjective::FeedOnTime { .. }) => true,
+            (QuestAction::FeedCow, QuestObjective::FeedCows { .. }) => true,
+            (QuestAction::BuyCow(breed), QuestObjective::BuyBreed { breed: quest_breed, .. }) => breed == quest_breed,
+            (QuestAction::SellCow, QuestObjective::SellCows { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+/// [Daily Quests]
+/// Root chain admin sets the quest pool and how many quests rotate in every day.
+/// Root chain and player chains both count progress, Root chain decides on the reward claim.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_quest_schedule(&self) -> Option<QuestSchedule> {
+        self.quest_schedule.get().clone()
+    }
+
+    pub fn set_quest_schedule(
+        &mut self,
+        quests: Vec<Quest>,
+        quests_per_day: u64,
+        start_time: Timestamp,
+    ) -> QuestSchedule {
+        if quests.is_empty() {
+            panic!("quest schedule must have at least 1 quest");
+        }
+        if quests_per_day == 0 || quests_per_day > quests.len() as u64 {
+            panic!("quests per day must be between 1 and {}", quests.len());
+        }
+        if quests.iter().any(|quest| quest.objective.count() == 0) {
+            panic!("quest objective count must be more than 0");
+        }
+
+        let schedule_id = *self.next_quest_schedule_id.get() + 1;
+        self.next_quest_schedule_id.set(schedule_id);
+        let quest_schedule = QuestSchedule { id: schedule_id, quests, quests_per_day, start_time };
+        self.quest_schedule.set(Some(quest_schedule.clone()));
+        quest_schedule
+    }
+
+    pub fn save_quest_schedule(&mut self, quest_schedule: Option<QuestSchedule>) {
+        self.quest_schedule.set(quest_schedule);
+    }
+
+    pub fn get_quest_day(&self, quest_schedule: &QuestSchedule, system_time: Timestamp) -> Option<u64> {
+        // quests start rotating at the schedule start time
+        let system_time = self.get_game_time(system_time);
+        if system_time < quest_schedule.start_time {
+            return None;
+        }
+        let elapsed = system_time.micros() - quest_schedule.start_time.micros();
+        Some(elapsed / UNIX_MICROS_IN_24_HOURS)
+    }
+
+    pub fn get_daily_quest_indices(&self, system_time: Timestamp) -> Vec<u64> {
+        let Some(quest_schedule) = self.get_quest_schedule() else {
+            return Vec::new();
+        };
+        let Some(day) = self.get_quest_day(&quest_schedule, system_time) else {
+            return Vec::new();
+        };
+        let pool_size = quest_schedule.quests.len() as u64;
+        (0..quest_schedule.quests_per_day)
+            .map(|position| (day * quest_schedule.quests_per_day + position) % pool_size)
+            .collect()
+    }
+
+    pub async fn get_quest_progress(&self, owner: Owner) -> Vec<QuestProgress> {
+        self.quest_progress.get(&owner).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Quest Progress Result");
+            })
+            .unwrap_or_default()
+    }
+
+    pub async fn get_daily_quests(&self, owner: Owner, system_time: Timestamp) -> Vec<DailyQuest> {
+        let Some(quest_schedule) = self.get_quest_schedule() else {
+            return Vec::new();
+        };
+        let Some(day) = self.get_quest_day(&quest_schedule, system_time) else {
+            return Vec::new();
+        };
+        let ends_at = quest_schedule.start_time.saturating_add_micros((day + 1) * UNIX_MICROS_IN_24_HOURS);
+        let quest_progress = self.get_quest_progress(owner).await;
+        let mut daily_quests = Vec::new();
+        for quest_index in self.get_daily_quest_indices(system_time).into_iter() {
+            let quest = quest_schedule.quests[quest_index as usize].clone();
+            let progress = quest_progress
+                .iter()
+                .find(|progress| {
+                    progress.schedule_id == quest_schedule.id && progress.day == day && progress.quest_index == quest_index
+                })
+                .cloned()
+                .unwrap_or_default();
+            daily_quests.push(DailyQuest {
+                quest_index,
+                is_completed: progress.progress >= quest.objective.count(),
+                is_claimed: progress.is_claimed,
+                progress: progress.progress,
+                quest,
+                ends_at,
+            });
+        }
+        daily_quests
+    }
+
+    pub async fn record_quest_action(&mut self, owner: Owner, action: QuestAction, system_time: Timestamp) {
+        for daily_quest in self.get_daily_quests(owner, system_time).await.into_iter() {
+            if !action.is_counted_by(&daily_quest.quest.objective) {
+                continue;
+            }
+            let mut progress = self.get_daily_quest_progress(daily_quest.quest_index, system_time);
+            progress.progress = daily_quest.progress + 1;
+            progress.is_claimed = daily_quest.is_claimed;
+            self.save_quest_progress(owner, progress).await;
+        }
+    }
+
+    pub async fn check_quest_claimable(
+        &self,
+        owner: Owner,
+        quest_index: u64,
+        system_time: Timestamp,
+    ) -> Result<(Quest, QuestProgress), String> {
+        let daily_quest = self.get_daily_quests(owner, system_time).await
+            .into_iter()
+            .find(|daily_quest| daily_quest.quest_index == quest_index)
+            .ok_or_else(|| format!("quest {} is not active today", quest_index))?;
+        if daily_quest.is_claimed {
+            return Err(format!("{:?} reward has been claimed", daily_quest.quest.name));
+        }
+        if !daily_quest.is_completed {
+            return Err(format!(
+                "{:?} is not completed, {} of {}",
+                daily_quest.quest.name, daily_quest.progress, daily_quest.quest.objective.count(),
+            ));
+        }
+
+        let mut progress = self.get_daily_quest_progress(quest_index, system_time);
+        progress.progress = daily_quest.progress;
+        Ok((daily_quest.quest, progress))
+    }
+
+    pub async fn save_quest_progress(&mut self, owner: Owner, quest_progress: QuestProgress) {
+        // progress of previous days or schedules is no longer kept
+        let mut progress_list: Vec<QuestProgress> = self.get_quest_progress(owner).await
+            .into_iter()
+            .filter(|progress| {
+                progress.schedule_id == quest_progress.schedule_id
+                    && progress.day == quest_progress.day
+                    && progress.quest_index != quest_progress.quest_index
+            })
+            .collect();
+        progress_list.push(quest_progress);
+        self.quest_progress
+            .insert(&owner, progress_list)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Quest Progress");
+            });
+    }
+
+    fn get_daily_quest_progress(&self, quest_index: u64, system_time: Timestamp) -> QuestProgress {
+        let quest_schedule = self.get_quest_schedule().unwrap_or_else(|| {
+            panic!("quest schedule is not set");
+        });
+        QuestProgress {
+            schedule_id: quest_schedule.id,
+            day: self.get_quest_day(&quest_schedule, system_time).unwrap_or_default(),
+            quest_index,
+            ..QuestProgress::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::{CowBreed, Quest, QuestObjective};
+    use crate::constants::*;
+    use crate::test_utils::{load_state, test_owner};
+    use super::QuestAction;
+
+    fn test_quests() -> Vec<Quest> {
+        vec![
+            Quest { name: String::from("Hungry herd"), objective: QuestObjective::FeedCows { count: 2 }, reward: Amount::from_tokens(50) },
+            Quest { name: String::from("Jersey lover"), objective: QuestObjective::BuyBreed { breed: CowBreed::Jersey, count: 1 }, reward: Amount::from_tokens(80) },
+        ]
+    }
+
+    #[test]
+    fn completed_quest_is_claimed_once() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let owner = test_owner("01");
+            let start_time = Timestamp::from(1_000);
+            state.set_quest_schedule(test_quests(), 1, start_time);
+
+            // day 0 rotates in the 1st quest only
+            let system_time = start_time.saturating_add_micros(1);
+            assert_eq!(state.get_daily_quest_indices(system_time), vec![0]);
+            state.record_quest_action(owner, QuestAction::BuyCow(CowBreed::Jersey), system_time).await;
+            state.record_quest_action(owner, QuestAction::FeedCow, system_time).await;
+            assert!(state.check_quest_claimable(owner, 0, system_time).await.is_err());
+            assert!(state.check_quest_claimable(owner, 1, system_time).await.is_err());
+
+            state.record_quest_action(owner, QuestAction::FeedCow, system_time).await;
+            let (quest, mut progress) = state.check_quest_claimable(owner, 0, system_time).await.unwrap();
+            assert_eq!(quest.reward, Amount::from_tokens(50));
+            assert_eq!(progress.progress, 2);
+
+            // root chain marks the reward as claimed after paying it
+            progress.is_claimed = true;
+            state.save_quest_progress(owner, progress).await;
+            assert!(state.check_quest_claimable(owner, 0, system_time).await.is_err());
+
+            // next day rotates in the 2nd quest, with a fresh progress
+            let next_day = start_time.saturating_add_micros(UNIX_MICROS_IN_24_HOURS);
+            assert_eq!(state.get_daily_quest_indices(next_day), vec![1]);
+            assert!(state.check_quest_claimable(owner, 1, next_day).await.is_err());
+            state.record_quest_action(owner, QuestAction::BuyCow(CowBreed::Jersey), next_day).await;
+            assert!(state.check_quest_claimable(owner, 1, next_day).await.is_ok());
+        });
+    }
+
+    #[test]
+    fn quests_wait_for_the_schedule_start() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let start_time = Timestamp::from(UNIX_MICROS_IN_24_HOURS);
+            state.set_quest_schedule(test_quests(), 2, start_time);
+            assert!(state.get_daily_quests(test_owner("01"), Timestamp::from(1_000)).await.is_empty());
+            assert_eq!(state.get_daily_quests(test_owner("01"), start_time).await.len(), 2);
+
+            state.save_quest_schedule(None);
+            assert!(state.get_daily_quests(test_owner("01"), start_time).await.is_empty());
+        });
+    }
+}
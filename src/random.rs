@@ -14,6 +14,26 @@ pub fn custom_getrandom(buf: &mut [u8], seed: [u8; 32]) -> Result<(), getrandom:
     Ok(())
 }
 
+pub fn seeded_getrandom(buf: &mut [u8], seed: [u8; 32]) -> Result<(), getrandom::Error> {
+    // fresh generator for every call, so the whole seed counts, not only the first one
+    StdRng::from_seed(seed).fill(buf);
+    Ok(())
+}
+
+pub fn mix_seed(parts: &[&[u8]]) -> [u8; 32] {
+    // fold every part into the seed, a change in any byte changes the seed
+    let mut seed = [0u8; 32];
+    let mut index = 0;
+    for part in parts.iter() {
+        for byte in part.iter().chain([0xff].iter()) {
+            let slot = index % seed.len();
+            seed[slot] = seed[slot].rotate_left(3) ^ byte.wrapping_mul(31).wrapping_add(index as u8);
+            index += 1;
+        }
+    }
+    seed
+}
+
 pub fn truncate(s: &str, max_chars: usize) -> &str {
     match s.char_indices().nth(max_chars) {
         None => s,
@@ -0,0 +1,126 @@
+use linera_sdk::base::{Amount, Owner, Timestamp};
+use crate::{DailyReward, DailyRewardStatus};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Daily Reward]
+/// Tokens that a player can claim once a day from Root chain treasury,
+/// with a bonus for every consecutive day.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub async fn get_daily_reward(&self, owner: Owner) -> Option<DailyReward> {
+        self.daily_rewards.get(&owner).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Daily Reward Result");
+            })
+    }
+
+    pub fn check_daily_reward_claimable(
+        &self,
+        daily_reward: Option<&DailyReward>,
+        system_time: Timestamp,
+    ) -> Result<(), String> {
+        // first claim is available right away
+        if let Some(daily_reward) = daily_reward {
+            let next_claim_time = daily_reward.last_claim_time.saturating_add_micros(DAILY_REWARD_COOLDOWN);
+            if system_time < next_claim_time {
+                return Err(format!("Daily reward can be claimed again at {}", next_claim_time.micros()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_next_streak(&self, daily_reward: Option<&DailyReward>, system_time: Timestamp) -> u64 {
+        // streak continues if the reward is claimed within the streak window
+        match daily_reward {
+            Some(daily_reward) if system_time <= self.get_streak_expire_time(daily_reward) => {
+                daily_reward.streak + 1
+            }
+            _ => 1,
+        }
+    }
+
+    pub fn get_daily_reward_amount(&self, streak: u64) -> Amount {
+        let bonus_days = streak.clamp(1, DAILY_REWARD_MAX_STREAK) - 1;
+        Amount::from_tokens(DAILY_REWARD + (bonus_days as u128) * DAILY_REWARD_STREAK_BONUS)
+    }
+
+    pub async fn get_daily_reward_status(&self, owner: Owner, system_time: Timestamp) -> DailyRewardStatus {
+        let daily_reward = self.get_daily_reward(owner).await;
+        let mut streak = 0;
+        let mut next_claim_time = system_time;
+        let mut streak_expire_time = None;
+        if let Some(daily_reward) = daily_reward.as_ref() {
+            let expire_time = self.get_streak_expire_time(daily_reward);
+            if system_time <= expire_time {
+                streak = daily_reward.streak;
+                streak_expire_time = Some(expire_time);
+            }
+            next_claim_time = next_claim_time.max(
+                daily_reward.last_claim_time.saturating_add_micros(DAILY_REWARD_COOLDOWN)
+            );
+        }
+
+        let next_streak = self.get_next_streak(daily_reward.as_ref(), next_claim_time);
+        DailyRewardStatus {
+            owner,
+            streak,
+            is_claimable: self.check_daily_reward_claimable(daily_reward.as_ref(), system_time).is_ok(),
+            next_claim_time,
+            streak_expire_time,
+            next_streak,
+            next_reward: self.get_daily_reward_amount(next_streak),
+        }
+    }
+
+    pub async fn claim_daily_reward(
+        &mut self,
+        owner: Owner,
+        streak: u64,
+        amount: Amount,
+        system_time: Timestamp,
+    ) -> DailyReward {
+        let mut total_claimed = self.get_daily_reward(owner).await
+            .map(|daily_reward| daily_reward.total_claimed)
+            .unwrap_or(Amount::ZERO);
+        total_claimed.saturating_add_assign(amount);
+        let daily_reward = DailyReward { owner, streak, last_claim_time: system_time, total_claimed };
+        self.save_daily_reward(daily_reward.clone());
+        daily_reward
+    }
+
+    pub fn save_daily_reward(&mut self, daily_reward: DailyReward) {
+        self.daily_rewards
+            .insert(&daily_reward.owner.clone(), daily_reward)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Daily Reward");
+            });
+    }
+
+    pub async fn resume_daily_rewards(&mut self, paused_at: Timestamp, paused_duration: u64) {
+        let mut daily_rewards = Vec::new();
+        self.daily_rewards
+            .for_each_index_value(|_, daily_reward| {
+                daily_rewards.push(daily_reward);
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read daily rewards");
+            });
+
+        // cooldown & streak window move forward, streak that expired before the game paused stays expired
+        for mut daily_reward in daily_rewards.into_iter() {
+            let is_expired = self.get_streak_expire_time(&daily_reward) < paused_at;
+            if is_expired || daily_reward.last_claim_time > paused_at {
+                continue;
+            }
+            daily_reward.last_claim_time = daily_reward.last_claim_time.saturating_add_micros(paused_duration);
+            self.save_daily_reward(daily_reward);
+        }
+    }
+
+    fn get_streak_expire_time(&self, daily_reward: &DailyReward) -> Timestamp {
+        daily_reward.last_claim_time.saturating_add_micros(DAILY_REWARD_STREAK_WINDOW)
+    }
+}
@@ -1,21 +1,17 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-mod state;
-mod constants;
-
 use std::sync::{Arc, Mutex};
 use async_graphql::{EmptySubscription, Schema};
 use async_graphql_derive::Object;
-use self::state::MicroCow;
+use micro_cow_linera::state::MicroCow;
 use linera_sdk::{
     base::WithServiceAbi,
     views::{View, ViewStorageContext},
     Service, ServiceRuntime,
 };
-use linera_sdk::base::{Amount, Owner, Timestamp};
+use linera_sdk::base::{Amount, ChainId, Owner, Timestamp};
 use linera_sdk::graphql::GraphQLMutationRoot;
-use micro_cow_linera::{BuyNotif, CowData, CowOperation, SellNotif};
-use crate::constants::WELL_FED;
+use micro_cow_linera::{AchievementNotif, BreedMarket, CaretakerGrant, CaretakerNotif, CoopNotif, Cooperative, BreedSupplyReport, BuyNotif, CowBreed, CowData, CowOperation, CowSellQuote, DailyQuest, DailyRewardStatus, FarmNotif, FarmStatus, FeedInventory, FeedNotif, FeedPrice, FeedingSchedule, FeedingStatus, GameConfig, HealthNotif, HerdStatsReport, InsuranceNotif, InsurancePolicyReport, InsuranceQuote, KeeperBudget, KeeperNotif, LoanNotif, LoanReport, PauseState, PlayerAchievement, PlayerData, QuestNotif, QuestSchedule, RenameNotif, RewardNotif, SeasonalEvent, SellNotif, ShopNotif, TreasuryHealth};
 
 #[derive(Clone)]
 pub struct MicroCowService {
@@ -33,9 +29,11 @@ impl Service for MicroCowService {
     type Parameters = ();
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
-        let state = MicroCow::load(ViewStorageContext::from(runtime.key_value_store()))
+        let mut state = MicroCow::load(ViewStorageContext::from(runtime.key_value_store()))
             .await
             .expect("Failed to load state");
+        // upgrade old records in memory only, contract will store them on its next block
+        state.migrate().await;
         MicroCowService {
             state: Arc::new(state),
             runtime: Arc::new(Mutex::new(runtime)),
@@ -52,6 +50,18 @@ impl Service for MicroCowService {
     }
 }
 
+impl MicroCowService {
+    fn system_time(&self, system_time: Option<Timestamp>) -> Timestamp {
+        // use service runtime clock, unless caller provide a time to check against
+        system_time.unwrap_or_else(|| {
+            self.runtime
+                .lock()
+                .expect("failed to get runtime lock")
+                .system_time()
+        })
+    }
+}
+
 /// ------------------------------------------------------------------------------------------
 #[Object]
 impl MicroCowService {
@@ -70,32 +80,184 @@ impl MicroCowService {
         appdata.balance
     }
     async fn get_all_buy_notifications(&self) -> Vec<BuyNotif> {
-        let buy_notifications = self.state
+        self.state
             .buy_notification
             .elements()
             .await
             .unwrap_or_else(|_| {
                 panic!("unable to read Buy notifications");
-            });
-        buy_notifications
+            })
     }
     async fn get_one_buy_notification(&self) -> Vec<BuyNotif> {
-        let notification = self.state.buy_notification.read_front(1).await.unwrap();
-        notification
+        self.state.buy_notification.read_front(1).await.unwrap()
     }
     async fn get_all_sell_notifications(&self) -> Vec<SellNotif> {
-        let buy_notifications = self.state
+        self.state
             .sell_notification
             .elements()
             .await
             .unwrap_or_else(|_| {
                 panic!("unable to read Sell notifications");
-            });
-        buy_notifications
+            })
     }
     async fn get_one_sell_notification(&self) -> Vec<SellNotif> {
-        let notification = self.state.sell_notification.read_front(1).await.unwrap();
-        notification
+        self.state.sell_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_feed_notifications(&self) -> Vec<FeedNotif> {
+        self.state
+            .feed_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Feed notifications");
+            })
+    }
+    async fn get_one_feed_notification(&self) -> Vec<FeedNotif> {
+        self.state.feed_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_reward_notifications(&self) -> Vec<RewardNotif> {
+        self.state
+            .reward_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Reward notifications");
+            })
+    }
+    async fn get_one_reward_notification(&self) -> Vec<RewardNotif> {
+        self.state.reward_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_loan_notifications(&self) -> Vec<LoanNotif> {
+        self.state
+            .loan_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Loan notifications");
+            })
+    }
+    async fn get_one_loan_notification(&self) -> Vec<LoanNotif> {
+        self.state.loan_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_insurance_notifications(&self) -> Vec<InsuranceNotif> {
+        self.state
+            .insurance_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Insurance notifications");
+            })
+    }
+    async fn get_one_insurance_notification(&self) -> Vec<InsuranceNotif> {
+        self.state.insurance_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_shop_notifications(&self) -> Vec<ShopNotif> {
+        self.state
+            .shop_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Shop notifications");
+            })
+    }
+    async fn get_one_shop_notification(&self) -> Vec<ShopNotif> {
+        self.state.shop_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_farm_notifications(&self) -> Vec<FarmNotif> {
+        self.state
+            .farm_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Farm notifications");
+            })
+    }
+    async fn get_one_farm_notification(&self) -> Vec<FarmNotif> {
+        self.state.farm_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_health_notifications(&self) -> Vec<HealthNotif> {
+        self.state
+            .health_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Health notifications");
+            })
+    }
+    async fn get_one_health_notification(&self) -> Vec<HealthNotif> {
+        self.state.health_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_achievement_notifications(&self) -> Vec<AchievementNotif> {
+        self.state
+            .achievement_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Achievement notifications");
+            })
+    }
+    async fn get_one_achievement_notification(&self) -> Vec<AchievementNotif> {
+        self.state.achievement_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_quest_notifications(&self) -> Vec<QuestNotif> {
+        self.state
+            .quest_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Quest notifications");
+            })
+    }
+    async fn get_one_quest_notification(&self) -> Vec<QuestNotif> {
+        self.state.quest_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_keeper_notifications(&self) -> Vec<KeeperNotif> {
+        self.state
+            .keeper_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Keeper notifications");
+            })
+    }
+    async fn get_one_keeper_notification(&self) -> Vec<KeeperNotif> {
+        self.state.keeper_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_caretaker_notifications(&self) -> Vec<CaretakerNotif> {
+        self.state
+            .caretaker_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Caretaker notifications");
+            })
+    }
+    async fn get_one_caretaker_notification(&self) -> Vec<CaretakerNotif> {
+        self.state.caretaker_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_coop_notifications(&self) -> Vec<CoopNotif> {
+        self.state
+            .coop_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Cooperative notifications");
+            })
+    }
+    async fn get_one_coop_notification(&self) -> Vec<CoopNotif> {
+        self.state.coop_notification.read_front(1).await.unwrap()
+    }
+    async fn get_all_rename_notifications(&self) -> Vec<RenameNotif> {
+        self.state
+            .rename_notification
+            .elements()
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read Rename notifications");
+            })
+    }
+    async fn get_one_rename_notification(&self) -> Vec<RenameNotif> {
+        self.state.rename_notification.read_front(1).await.unwrap()
     }
     async fn get_my_cows(&self) -> Vec<CowData> {
         self.state.get_my_cows().await
@@ -117,16 +279,19 @@ impl MicroCowService {
         cow_list.push(data);
         cow_list
     }
-    async fn is_cow_alive(&self, cow_name: String, system_time: Timestamp) -> bool {
+    async fn is_cow_alive(&self, cow_name: String, system_time: Option<Timestamp>) -> bool {
+        let system_time = self.system_time(system_time);
         self.state.is_cow_alive_and_exist(cow_name.clone(), system_time).await
     }
     async fn get_cow_existence(&self, cow_name: String) -> bool {
         self.state.is_cow_exist_in_db_and_ownership(cow_name).await
     }
-    async fn is_cow_underage(&self, cow_born_time: Timestamp, system_time: Timestamp) -> bool {
+    async fn is_cow_underage(&self, cow_born_time: Timestamp, system_time: Option<Timestamp>) -> bool {
+        let system_time = self.system_time(system_time);
         self.state.is_cow_underage(cow_born_time, system_time).await
     }
-    async fn get_cow_sell_value(&self, cow_name: String) -> Amount {
+    async fn get_cow_sell_value(&self, cow_name: String, system_time: Option<Timestamp>) -> Amount {
+        let system_time = self.system_time(system_time);
         // check if name exist
         let is_exist = self.state.cow_db.contains_key(&cow_name).await.unwrap_or(false);
         if !is_exist {
@@ -141,32 +306,187 @@ impl MicroCowService {
             panic!("unable to get Cow DB Option");
         });
         // get sell value
-        self.state.cow_sell_value(cow).await
+        self.state.cow_sell_value(cow, system_time).await
     }
-    async fn is_cow_still_full(&self, cow_name: String, system_time: Timestamp) -> bool {
+    async fn get_cow_sell_quote(&self, cow_name: String, system_time: Option<Timestamp>) -> Vec<CowSellQuote> {
+        let system_time = self.system_time(system_time);
+        let mut quote_list = Vec::new();
         // check if name exist
         let is_exist = self.state.cow_db.contains_key(&cow_name).await.unwrap_or(false);
         if !is_exist {
-            return true;
+            return quote_list;
         }
 
         // get CowData from DB
-        let mut cow = self.state.cow_db.get(&cow_name).await
+        let cow = self.state.cow_db.get(&cow_name).await
             .unwrap_or_else(|_| {
                 panic!("unable to get Cow DB Result");
             }).unwrap_or_else(|| {
             panic!("unable to get Cow DB Option");
         });
 
-        // find out feeding distance
-        let current_time = system_time.micros();
-        let last_fed_time = cow.last_fed_time.micros();
-        let feed_distance = current_time - last_fed_time;
+        // get sell quote based on cow breed price
+        let cow_base_price = self.state.get_cow_price(cow.breed);
+        quote_list.push(self.state.get_cow_sell_quote(&cow, cow_base_price, system_time));
+        quote_list
+    }
+    async fn is_cow_still_full(&self, cow_name: String, system_time: Option<Timestamp>) -> bool {
+        let system_time = self.system_time(system_time);
 
-        if feed_distance <= WELL_FED {
+        // check if name exist
+        let is_exist = self.state.cow_db.contains_key(&cow_name).await.unwrap_or(false);
+        if !is_exist {
             return true;
         }
-        false
+
+        // get CowData from DB
+        let cow = self.state.cow_db.get(&cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            }).unwrap_or_else(|| {
+            panic!("unable to get Cow DB Option");
+        });
+
+        // find out feeding status
+        self.state.get_cow_status(&cow, system_time) == FeedingStatus::Full
+    }
+    async fn get_my_cows_feeding_schedule(&self, system_time: Option<Timestamp>) -> Vec<FeedingSchedule> {
+        let system_time = self.system_time(system_time);
+        self.state
+            .get_my_cows()
+            .await
+            .iter()
+            .map(|cow| self.state.get_feeding_schedule(cow, system_time))
+            .collect()
+    }
+    async fn get_herd_stats(&self, system_time: Option<Timestamp>) -> HerdStatsReport {
+        let system_time = self.system_time(system_time);
+        self.state.get_herd_stats_report(system_time).await
+    }
+    async fn get_player(&self, owner: Owner) -> Vec<PlayerData> {
+        self.state.get_player(owner).await.into_iter().collect()
+    }
+    async fn get_player_by_chain(&self, chain_id: ChainId) -> Vec<PlayerData> {
+        self.state.get_player_by_chain(chain_id).await.into_iter().collect()
+    }
+    async fn get_game_config(&self) -> GameConfig {
+        self.state.get_game_config()
+    }
+    async fn get_admins(&self) -> Vec<Owner> {
+        self.state.get_admins()
+    }
+    async fn get_pause_state(&self) -> PauseState {
+        self.state.pause_state.get().clone()
+    }
+    async fn get_treasury_health(&self, system_time: Option<Timestamp>) -> TreasuryHealth {
+        let system_time = self.system_time(system_time);
+        self.state.get_treasury_health(system_time).await
+    }
+    async fn get_breed_markets(&self) -> Vec<BreedMarket> {
+        self.state.get_breed_markets()
+    }
+    async fn get_breed_supplies(&self, system_time: Option<Timestamp>) -> Vec<BreedSupplyReport> {
+        let system_time = self.system_time(system_time);
+        let mut supply_list = Vec::new();
+        for breed in CowBreed::ALL.iter() {
+            supply_list.push(self.state.get_breed_supply_report(*breed, system_time).await);
+        }
+        supply_list
+    }
+    async fn get_cow_price(&self, cow_breed: CowBreed, system_time: Option<Timestamp>) -> Amount {
+        let system_time = self.system_time(system_time);
+        self.state.get_event_cow_price(cow_breed, system_time)
+    }
+    async fn get_seasonal_events(&self, system_time: Option<Timestamp>) -> Vec<SeasonalEvent> {
+        let system_time = self.system_time(system_time);
+        self.state.get_current_and_upcoming_events(system_time)
+    }
+    async fn get_daily_reward_status(&self, owner: Owner, system_time: Option<Timestamp>) -> DailyRewardStatus {
+        let system_time = self.system_time(system_time);
+        self.state.get_daily_reward_status(owner, system_time).await
+    }
+    async fn get_loans(&self, owner: Option<Owner>, system_time: Option<Timestamp>) -> Vec<LoanReport> {
+        let system_time = self.system_time(system_time);
+        let mut loan_list = Vec::new();
+        for loan in self.state.get_loans(owner).await.into_iter() {
+            loan_list.push(self.state.get_loan_report(loan, system_time).await);
+        }
+        loan_list
+    }
+    async fn get_max_loan(&self, owner: Owner, cow_names: Vec<String>, system_time: Option<Timestamp>) -> Amount {
+        let system_time = self.system_time(system_time);
+        match self.state.check_loan_collateral(owner, &cow_names, system_time).await {
+            Ok(cows) => self.state.get_max_loan(cows, system_time).await,
+            Err(_) => Amount::ZERO,
+        }
+    }
+    async fn get_insurance_policies(
+        &self,
+        owner: Option<Owner>,
+        system_time: Option<Timestamp>,
+    ) -> Vec<InsurancePolicyReport> {
+        let system_time = self.system_time(system_time);
+        let mut policy_list = Vec::new();
+        for policy in self.state.get_policies(owner).await.into_iter() {
+            policy_list.push(self.state.get_policy_report(policy, system_time).await);
+        }
+        policy_list
+    }
+    async fn get_insurance_quote(&self, cow_name: String, system_time: Option<Timestamp>) -> Vec<InsuranceQuote> {
+        let system_time = self.system_time(system_time);
+        let mut quote_list = Vec::new();
+        // check if name exist
+        let cow = self.state.cow_db.get(&cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            });
+        if let Some(cow) = cow {
+            quote_list.push(self.state.get_insurance_quote(cow, system_time).await);
+        }
+        quote_list
+    }
+    async fn get_feed_prices(&self) -> Vec<FeedPrice> {
+        self.state.get_feed_prices()
+    }
+    async fn get_feed_inventory(&self, owner: Owner) -> FeedInventory {
+        self.state.get_feed_inventory(owner).await
+    }
+    async fn get_quest_schedule(&self) -> Option<QuestSchedule> {
+        self.state.get_quest_schedule()
+    }
+    async fn get_daily_quests(&self, owner: Owner, system_time: Option<Timestamp>) -> Vec<DailyQuest> {
+        let system_time = self.system_time(system_time);
+        self.state.get_daily_quests(owner, system_time).await
+    }
+    async fn get_achievements(&self, owner: Owner) -> Vec<PlayerAchievement> {
+        self.state.get_achievements(owner).await
+    }
+    async fn get_treatment_price(&self) -> Amount {
+        self.state.get_treatment_price()
+    }
+    async fn get_keeper_budgets(&self, owner: Option<Owner>) -> Vec<KeeperBudget> {
+        self.state.get_keeper_budgets(owner).await
+    }
+    async fn get_keeper_jobs(&self, system_time: Option<Timestamp>) -> Vec<KeeperBudget> {
+        let system_time = self.system_time(system_time);
+        self.state.get_keeper_jobs(system_time).await
+    }
+    async fn get_caretaker_grants(&self, owner: Option<Owner>, caretaker: Option<Owner>) -> Vec<CaretakerGrant> {
+        self.state.get_all_caretaker_grants(owner, caretaker).await
+    }
+    async fn get_cooperatives(&self, owner: Option<Owner>) -> Vec<Cooperative> {
+        self.state.get_cooperatives(owner).await
+    }
+    async fn get_coop_herd(&self, coop_id: u64, system_time: Option<Timestamp>) -> Vec<CowData> {
+        let system_time = self.system_time(system_time);
+        self.state.get_coop_herd(coop_id, system_time).await
+    }
+    async fn get_farm_status(&self, owner: Owner, system_time: Option<Timestamp>) -> FarmStatus {
+        let system_time = self.system_time(system_time);
+        self.state.get_farm_status(owner, system_time).await
+    }
+    async fn get_storage_version(&self) -> u32 {
+        self.state.get_storage_version()
     }
     async fn count_all_cow_in_local_db(&self) -> usize {
         let cow_keys = self.state.cow_db.indices().await
@@ -175,4 +495,75 @@ impl MicroCowService {
             });
         cow_keys.len()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use async_graphql::{value, Request, Value};
+    use futures::executor::block_on;
+    use linera_sdk::{Service, ServiceRuntime};
+    use linera_sdk::base::{Amount, ChainId, Owner, Timestamp};
+    use linera_sdk::views::{RootView, View, ViewStorageContext};
+    use micro_cow_linera::{CowBreed, CowData, CowGender, FeedingStats};
+    use micro_cow_linera::constants::*;
+    use super::{MicroCow, MicroCowService};
+
+    const START_TIME: u64 = 10 * UNIX_MICROS_IN_24_HOURS;
+
+    fn service_with_cow(last_fed_time: u64, system_time: u64) -> MicroCowService {
+        let owner = Owner::from_str(&"01".repeat(32)).unwrap();
+        let runtime = ServiceRuntime::<MicroCowService>::new().with_system_time(Timestamp::from(system_time));
+        let context = ViewStorageContext::from(runtime.key_value_store().to_mut());
+        let mut state = block_on(MicroCow::load(context)).unwrap();
+        block_on(state.migrate());
+        block_on(state.initialize(owner, ChainId::root(1), Amount::from_tokens(USER_INITIAL_TOKEN), false));
+        let cow = CowData::new(
+            String::from("cow-id"),
+            String::from("Bessie"),
+            CowBreed::Jersey,
+            CowGender::Female,
+            Timestamp::from(0),
+            Timestamp::from(last_fed_time),
+            FeedingStats::new(),
+            owner,
+        );
+        block_on(state.create_new_or_update_cow_data(String::from("Bessie"), cow));
+        block_on(state.update_cow_ownership(String::from("Bessie")));
+        block_on(state.save()).unwrap();
+        block_on(MicroCowService::new(runtime))
+    }
+
+    fn query(service: &MicroCowService, query: &str) -> Value {
+        let response = block_on(service.handle_query(Request::new(query)));
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data
+    }
+
+    #[test]
+    fn time_queries_use_the_chain_clock() {
+        let service = service_with_cow(START_TIME, START_TIME + UNIX_MICROS_IN_24_HOURS + 1);
+        assert_eq!(
+            query(&service, r#"{ isCowAlive(cowName: "Bessie") }"#),
+            value!({ "isCowAlive": false }),
+        );
+        assert_eq!(
+            query(&service, r#"{ isCowStillFull(cowName: "Bessie") }"#),
+            value!({ "isCowStillFull": false }),
+        );
+    }
+
+    #[test]
+    fn time_queries_accept_a_time_override() {
+        let service = service_with_cow(START_TIME, START_TIME + UNIX_MICROS_IN_24_HOURS + 1);
+        let system_time = START_TIME + WELL_FED;
+        assert_eq!(
+            query(&service, &format!(r#"{{ isCowAlive(cowName: "Bessie", systemTime: {}) }}"#, system_time)),
+            value!({ "isCowAlive": true }),
+        );
+        assert_eq!(
+            query(&service, &format!(r#"{{ isCowStillFull(cowName: "Bessie", systemTime: {}) }}"#, system_time)),
+            value!({ "isCowStillFull": true }),
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use linera_sdk::base::{Amount, ChainId, Owner, Timestamp};
 use linera_sdk::views::{linera_views, MapView, QueueView, RegisterView, RootView, ViewStorageContext};
-use micro_cow_linera::{AccountData, BuyNotif, CowBreed, CowData, SellNotif};
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::BTreeSet;
+use crate::{AccountData, AchievementNotif, AchievementRecord, BreedMarket, CaretakerGrant, CaretakerNotif, CoopNotif, Cooperative, BreedPopulation, BreedPrice, BreedSupply, BuyNotif, CowBreed, CowData, CowHealth, DailyReward, CowGender, CowSellQuote, FarmNotif, FeedInventory, FeedNotif, FeedingSchedule, FeedingConfig, FeedingStats, FeedingStatus, GameConfig, HerdStats, HerdStatsReport, HealthNotif, InsuranceNotif, InsurancePolicy, KeeperBudget, KeeperNotif, LiabilityCache, Loan, LoanNotif, PauseState, PlayerData, QuestNotif, QuestProgress, QuestSchedule, RenameNotif, RewardNotif, SeasonalEvent, SellNotif, ShopNotif};
 use crate::constants::*;
 
 #[derive(RootView, async_graphql::SimpleObject)]
@@ -11,6 +13,51 @@ pub struct MicroCow {
     pub cow_ownership: MapView<String, u8>,
     pub buy_notification: QueueView<BuyNotif>,
     pub sell_notification: QueueView<SellNotif>,
+    pub feed_notification: QueueView<FeedNotif>,
+    pub herd_stats: RegisterView<HerdStats>,
+    pub players: MapView<Owner, PlayerData>,
+    pub player_chains: MapView<ChainId, Owner>,
+    pub game_config: RegisterView<Option<GameConfig>>,
+    pub admins: RegisterView<Vec<Owner>>,
+    pub pause_state: RegisterView<PauseState>,
+    pub min_reserve_ratio: RegisterView<Option<u64>>,
+    pub breed_markets: RegisterView<Vec<BreedMarket>>,
+    pub breed_supplies: RegisterView<Vec<BreedSupply>>,
+    pub storage_version: RegisterView<u32>,
+    pub seasonal_events: RegisterView<Vec<SeasonalEvent>>,
+    pub next_event_id: RegisterView<u64>,
+    pub daily_rewards: MapView<Owner, DailyReward>,
+    pub reward_notification: QueueView<RewardNotif>,
+    pub loans: MapView<u64, Loan>,
+    pub cow_loans: MapView<String, u64>,
+    pub next_loan_id: RegisterView<u64>,
+    pub loan_notification: QueueView<LoanNotif>,
+    pub insurance_policies: MapView<u64, InsurancePolicy>,
+    pub cow_policies: MapView<String, u64>,
+    pub next_policy_id: RegisterView<u64>,
+    pub insurance_notification: QueueView<InsuranceNotif>,
+    pub feed_inventories: MapView<Owner, FeedInventory>,
+    pub shop_notification: QueueView<ShopNotif>,
+    pub farm_levels: MapView<Owner, u64>,
+    pub farm_notification: QueueView<FarmNotif>,
+    pub health_notification: QueueView<HealthNotif>,
+    pub achievements: MapView<Owner, AchievementRecord>,
+    pub achievement_notification: QueueView<AchievementNotif>,
+    pub quest_schedule: RegisterView<Option<QuestSchedule>>,
+    pub next_quest_schedule_id: RegisterView<u64>,
+    pub quest_progress: MapView<Owner, Vec<QuestProgress>>,
+    pub quest_notification: QueueView<QuestNotif>,
+    pub rename_notification: QueueView<RenameNotif>,
+    pub cooperatives: MapView<u64, Cooperative>,
+    pub next_coop_id: RegisterView<u64>,
+    pub coop_notification: QueueView<CoopNotif>,
+    pub caretaker_grants: MapView<Owner, Vec<CaretakerGrant>>,
+    pub caretaker_notification: QueueView<CaretakerNotif>,
+    pub keeper_budgets: MapView<String, KeeperBudget>,
+    pub keeper_notification: QueueView<KeeperNotif>,
+    pub liability_cache: RegisterView<Option<LiabilityCache>>,
+    pub random_nonce: RegisterView<u64>,
+    pub total_removed: RegisterView<u64>,
 }
 
 /// ------------------------------------------------------------------------------------------
@@ -57,18 +104,13 @@ impl MicroCow {
             });
     }
 
-    pub async fn delete_buy_notification(&mut self) {
-        let count = self.buy_notification.count();
-        if count != 0 {
-            self.buy_notification.delete_front();
-        };
-    }
-
-    pub async fn delete_sell_notification(&mut self) {
-        let count = self.sell_notification.count();
-        if count != 0 {
-            self.sell_notification.delete_front();
-        };
+    pub fn delete_notification<T>(notifications: &mut QueueView<T>)
+    where
+        T: Clone + Send + Sync + Serialize + DeserializeOwned,
+    {
+        if notifications.count() != 0 {
+            notifications.delete_front();
+        }
     }
 
     pub async fn is_cow_alive_and_exist(&self, cow_name: String, system_time: Timestamp) -> bool {
@@ -81,9 +123,7 @@ impl MicroCow {
                 }).unwrap_or_else(|| {
                 panic!("unable to get Cow DB Option");
             });
-            let last_fed_after_24_hours = cow.last_fed_time.micros() + UNIX_MICROS_IN_24_HOURS;
-            let current_time = system_time.micros();
-            if current_time.gt(&last_fed_after_24_hours) {
+            if self.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
                 // cow last fed time is more than 24 hours
                 // therefore it has died, and everyone can claim it.
                 return false;
@@ -105,15 +145,224 @@ impl MicroCow {
     }
 
     pub fn get_cow_price(&self, breed: CowBreed) -> Amount {
-        let cow_price = match breed {
-            CowBreed::Jersey => JERSEY_PRICE,
-            CowBreed::Limousin => LIMOUSIN_PRICE,
-            CowBreed::Hallikar => HALLIKAR_PRICE,
-            CowBreed::Hereford => HEREFORD_PRICE,
-            CowBreed::Holstein => HOLSTEIN_PRICE,
-            CowBreed::Simmental => SIMMENTAL_PRICE,
-        };
-        Amount::from_tokens(cow_price)
+        self.get_game_config()
+            .cow_prices
+            .iter()
+            .find(|cow_price| cow_price.breed == breed)
+            .map(|cow_price| cow_price.price)
+            .unwrap_or_else(|| {
+                panic!("unable to get {:?} price", breed);
+            })
+    }
+
+    pub fn get_game_config(&self) -> GameConfig {
+        // use the default config from constants, until admin change it
+        self.game_config.get().clone().unwrap_or_else(|| {
+            let cow_price = |breed: CowBreed, price: u128| BreedPrice { breed, price: Amount::from_tokens(price) };
+            GameConfig {
+                cow_prices: vec![
+                    cow_price(CowBreed::Jersey, JERSEY_PRICE),
+                    cow_price(CowBreed::Limousin, LIMOUSIN_PRICE),
+                    cow_price(CowBreed::Hallikar, HALLIKAR_PRICE),
+                    cow_price(CowBreed::Hereford, HEREFORD_PRICE),
+                    cow_price(CowBreed::Holstein, HOLSTEIN_PRICE),
+                    cow_price(CowBreed::Simmental, SIMMENTAL_PRICE),
+                ],
+                feeding_config: FeedingConfig {
+                    well_fed: WELL_FED,
+                    on_time_feed: ON_TIME_FEED,
+                    late_feed: LATE_FEED,
+                    starving: UNIX_MICROS_IN_24_HOURS,
+                    on_time_reward: ON_TIME_REWARD as u64,
+                    late_reward: LATE_REWARD as u64,
+                    forgot_penalty: FORGOT_PENALTY as u64,
+                },
+            }
+        })
+    }
+
+    pub fn set_cow_price(&mut self, breed: CowBreed, price: Amount) {
+        let mut game_config = self.get_game_config();
+        for cow_price in game_config.cow_prices.iter_mut() {
+            if cow_price.breed == breed {
+                cow_price.price = price;
+            }
+        }
+        self.game_config.set(Some(game_config));
+    }
+
+    pub fn set_feeding_config(&mut self, feeding_config: FeedingConfig) {
+        // feeding time zone must be in order
+        let is_in_order = feeding_config.well_fed < feeding_config.on_time_feed
+            && feeding_config.on_time_feed < feeding_config.late_feed
+            && feeding_config.late_feed < feeding_config.starving;
+        if !is_in_order {
+            panic!("feeding time zone must be in order: well fed, on time, late, starving");
+        }
+        let mut game_config = self.get_game_config();
+        game_config.feeding_config = feeding_config;
+        self.game_config.set(Some(game_config));
+    }
+
+    pub fn is_admin(&self, owner: Owner) -> bool {
+        // root chain owner is the admin, until admin list is set
+        let admins = self.admins.get();
+        if admins.is_empty() {
+            return self.app_data.get().owner == owner;
+        }
+        admins.contains(&owner)
+    }
+
+    pub fn get_admins(&self) -> Vec<Owner> {
+        let admins = self.admins.get().clone();
+        if admins.is_empty() {
+            return vec![self.app_data.get().owner];
+        }
+        admins
+    }
+
+    pub fn add_admin(&mut self, admin: Owner) {
+        let mut admins = self.get_admins();
+        if !admins.contains(&admin) {
+            admins.push(admin);
+        }
+        self.admins.set(admins);
+    }
+
+    pub fn remove_admin(&mut self, admin: Owner) {
+        let mut admins = self.get_admins();
+        admins.retain(|owner| owner.ne(&admin));
+        if admins.is_empty() {
+            panic!("unable to remove the last admin");
+        }
+        self.admins.set(admins);
+    }
+
+    pub fn next_random_nonce(&mut self) -> u64 {
+        // every roll gets its own nonce, so rolls in the same block differ
+        let random_nonce = *self.random_nonce.get() + 1;
+        self.random_nonce.set(random_nonce);
+        random_nonce
+    }
+
+    pub fn get_game_time(&self, system_time: Timestamp) -> Timestamp {
+        // hunger clock is frozen while the game is paused
+        let pause_state = self.pause_state.get();
+        if pause_state.is_paused && pause_state.paused_at < system_time {
+            return pause_state.paused_at;
+        }
+        system_time
+    }
+
+    pub fn check_game_not_paused(&self) -> Result<(), String> {
+        let pause_state = self.pause_state.get();
+        if pause_state.is_paused {
+            return Err(format!("Micro Cow is paused: {}", pause_state.reason));
+        }
+        Ok(())
+    }
+
+    pub async fn resume_game(&mut self, paused_at: Timestamp, resumed_at: Timestamp) {
+        self.pause_state.set(PauseState::default());
+
+        // move every cow last fed time forward, so the paused duration doesn't count
+        let paused_duration = resumed_at.micros().saturating_sub(paused_at.micros());
+        let mut cows = Vec::new();
+        self.cow_db
+            .for_each_index_value(|_, cow| {
+                cows.push(cow);
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cow db");
+            });
+        for mut cow in cows.into_iter() {
+            // cow that has died before the game paused, stay dead
+            let is_dead = self.get_cow_status(&cow, paused_at) == FeedingStatus::Dead;
+            if is_dead || cow.last_fed_time > paused_at {
+                continue;
+            }
+            cow.last_fed_time = cow.last_fed_time.saturating_add_micros(paused_duration);
+            if let CowHealth::Sick { sick_since } = cow.health {
+                cow.health = CowHealth::Sick { sick_since: sick_since.saturating_add_micros(paused_duration) };
+            }
+            self.create_new_or_update_cow_data(cow.name.clone(), cow).await;
+        }
+
+        // deadlines that follow the hunger clock move forward too
+        // seasonal events & daily quests keep their calendar, they end at the same time
+        self.resume_loans(paused_at, paused_duration).await;
+        self.resume_policies(paused_at, paused_duration).await;
+        self.resume_daily_rewards(paused_at, paused_duration).await;
+        self.resume_caretaker_grants(paused_at, paused_duration).await;
+    }
+
+    pub async fn rename_cow(&mut self, cow_name: String, new_name: String) {
+        // move CowData to the new name
+        let cow = self.cow_db.get(&cow_name).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Cow DB Result");
+            });
+        if let Some(mut cow) = cow {
+            cow.name = new_name.clone();
+            self.remove_cow(cow_name.clone()).await;
+            self.create_new_or_update_cow_data(new_name.clone(), cow).await;
+        }
+
+        // move ownership to the new name
+        let in_ownership = self.cow_ownership.contains_key(&cow_name).await.unwrap_or(false);
+        if in_ownership {
+            self.cow_ownership.remove(&cow_name).unwrap_or_else(|_| {
+                panic!("unable to remove Cow from ownership");
+            });
+            self.update_cow_ownership(new_name.clone()).await;
+        }
+
+        // granted caretakers keep feeding the cow under the new name
+        self.rename_caretaker_grant_cow(&cow_name, &new_name).await;
+
+        // move keeper budget to the new name
+        if let Some(mut keeper_budget) = self.get_keeper_budget(&cow_name).await {
+            self.remove_keeper_budget(&cow_name);
+            keeper_budget.cow_name = new_name.clone();
+            self.save_keeper_budget(keeper_budget);
+        }
+
+        // move loan & insurance policy to the new name
+        if let Some(mut loan) = self.get_cow_loan(&cow_name).await {
+            self.close_loan(&loan);
+            for pledged_cow_name in loan.cow_names.iter_mut() {
+                if *pledged_cow_name == cow_name {
+                    *pledged_cow_name = new_name.clone();
+                }
+            }
+            self.save_loan(loan);
+        }
+        if let Some(mut policy) = self.get_cow_policy(&cow_name).await {
+            self.close_policy(&policy);
+            policy.cow_name = new_name;
+            self.save_policy(policy);
+        }
+    }
+
+    pub async fn check_cow_name_available(&self, new_name: &String) -> Result<(), String> {
+        if new_name.trim().is_empty() || new_name.trim().len() != new_name.len() {
+            return Err(format!("{:?} is not a valid name", new_name));
+        }
+        if new_name.chars().count() > COW_NAME_MAX_LENGTH {
+            return Err(format!("cow name must be at most {} characters", COW_NAME_MAX_LENGTH));
+        }
+        if self.cow_db.contains_key(new_name).await.unwrap_or(false) {
+            return Err(format!("{:?} is not available", new_name));
+        }
+        Ok(())
+    }
+
+    pub async fn remove_cow(&mut self, cow_name: String) {
+        self.cow_db.remove(&cow_name).unwrap_or_else(|_| {
+            panic!("unable to remove Cow from DB");
+        });
     }
 
     pub async fn is_cow_underage(&self, cow_born_time: Timestamp, system_time: Timestamp) -> bool {
@@ -125,16 +374,58 @@ impl MicroCow {
         false
     }
 
-    pub async fn cow_sell_value(&self, cow: CowData) -> Amount {
+    pub async fn cow_sell_value(&self, cow: CowData, system_time: Timestamp) -> Amount {
         // get cow price based on their breed
-        let cow_base_price = self.get_cow_price(cow.breed.clone());
+        let cow_base_price = self.get_cow_price(cow.breed);
 
         // get cow appraisal price
-        let cow_price_appraisal = self.get_cow_appraisal_price(
-            cow, cow_base_price,
-        ).await;
+        self.get_cow_appraisal_price(cow, cow_base_price, system_time).await
+    }
 
-        cow_price_appraisal
+    pub fn get_feeding_status(&self, last_fed_time: Timestamp, system_time: Timestamp) -> FeedingStatus {
+        // we have 4 feeding time zone, that is every 6 hours.
+        // 1st 6 hours -> FULL
+        // 2nd 6 hours -> ON TIME
+        // 3rd 6 hours -> LATE
+        // 4th 6 hours -> FORGET
+        // if feeding distance are more than 24 hours, the cow has died.
+        let system_time = self.get_game_time(system_time);
+        let config = self.get_feeding_config_at(system_time);
+        let feed_distance = system_time.micros().saturating_sub(last_fed_time.micros());
+        if feed_distance > config.starving {
+            return FeedingStatus::Dead;
+        }
+        if feed_distance > config.late_feed {
+            return FeedingStatus::Forgot;
+        }
+        if feed_distance > config.on_time_feed {
+            return FeedingStatus::Late;
+        }
+        if feed_distance > config.well_fed {
+            return FeedingStatus::OnTime;
+        }
+        FeedingStatus::Full
+    }
+
+    pub fn get_feeding_schedule(&self, cow: &CowData, system_time: Timestamp) -> FeedingSchedule {
+        let config = self.get_feeding_config_at(self.get_game_time(system_time));
+        let last_fed_time = cow.last_fed_time;
+
+        // sick cow dies early, unless it's treated
+        let mut death_at = last_fed_time.saturating_add_micros(config.starving);
+        if let Some(sick_death_at) = self.get_sick_death_time(cow) {
+            death_at = death_at.min(sick_death_at);
+        }
+        FeedingSchedule {
+            cow_name: cow.name.clone(),
+            status: self.get_cow_status(cow, system_time),
+            health: cow.health,
+            last_fed_time,
+            on_time_at: last_fed_time.saturating_add_micros(config.well_fed),
+            late_at: last_fed_time.saturating_add_micros(config.on_time_feed),
+            forgot_at: last_fed_time.saturating_add_micros(config.late_feed),
+            death_at,
+        }
     }
 
     pub async fn get_my_cows(&self) -> Vec<CowData> {
@@ -166,36 +457,276 @@ impl MicroCow {
         cow_list
     }
 
-    pub async fn get_cow_appraisal_price(&self, cow: CowData, cow_base_price: Amount) -> Amount {
-        // calculate appraisal multiplier
-        let on_time_rewards = (cow.feeding_stats.on_time as i128) * ON_TIME_REWARD;
-        let late_rewards = (cow.feeding_stats.late as i128) * LATE_REWARD;
-        let forgot_penalties = (cow.feeding_stats.forgot as i128) * FORGOT_PENALTY;
-        let mut rewards_penalties_multiplier = on_time_rewards + late_rewards - forgot_penalties;
+    pub async fn get_cow_appraisal_price(&self, cow: CowData, cow_base_price: Amount, system_time: Timestamp) -> Amount {
+        self.get_cow_sell_quote(&cow, cow_base_price, system_time).payout
+    }
+
+    pub fn get_cow_sell_quote(&self, cow: &CowData, cow_base_price: Amount, system_time: Timestamp) -> CowSellQuote {
+        // calculate appraisal multiplier, events only change the feedings made while they were active
+        let config = self.get_game_config().feeding_config;
+        let on_time_rewards = (cow.feeding_stats.on_time as i128) * (config.on_time_reward as i128);
+        let late_rewards = (cow.feeding_stats.late as i128) * (config.late_reward as i128);
+        let forgot_penalties = (cow.feeding_stats.forgot as i128) * (config.forgot_penalty as i128);
+        let event_bonus = cow.event_bonus as i128;
+        let mut rewards_penalties_multiplier = on_time_rewards + late_rewards - forgot_penalties + event_bonus;
 
         // check if the multiplier is a reward or a penalty
         let mut is_reward = true;
         if rewards_penalties_multiplier < 0 {
             is_reward = false;
-            rewards_penalties_multiplier = rewards_penalties_multiplier * -1;
+            rewards_penalties_multiplier = -rewards_penalties_multiplier;
         }
 
         // if it's a penalty, check if the penalty more than 100%
         // penalty must not exceed 100%
+        let mut is_penalty_capped = false;
         if !is_reward && rewards_penalties_multiplier > PRECISION_100_PERCENT {
+            is_penalty_capped = true;
             rewards_penalties_multiplier = PRECISION_100_PERCENT;
         }
 
         // calculate the amount of reward or penalty
-        let mut rewards_or_penalty = cow_base_price
-            .saturating_mul(rewards_penalties_multiplier as u128);
-        rewards_or_penalty = Amount::from_tokens(rewards_or_penalty
-            .saturating_div(Amount::from_tokens(PRECISION_100_PERCENT as u128)));
+        let rewards_or_penalty = self.get_appraisal_amount(cow_base_price, rewards_penalties_multiplier);
 
-        // calculate and return the appraisal price
+        // calculate the appraisal price
+        let mut payout = cow_base_price.saturating_add(rewards_or_penalty);
         if !is_reward {
-            return cow_base_price.saturating_sub(rewards_or_penalty);
+            payout = cow_base_price.saturating_sub(rewards_or_penalty);
+        }
+
+        // sick cow loses value for every day of sickness
+        let sickness_penalty = self.get_sickness_penalty(cow, cow_base_price, system_time);
+        payout = payout.saturating_sub(sickness_penalty);
+
+        CowSellQuote {
+            cow_name: cow.name.clone(),
+            base_price: cow_base_price,
+            on_time_reward: self.get_appraisal_amount(cow_base_price, on_time_rewards),
+            late_reward: self.get_appraisal_amount(cow_base_price, late_rewards),
+            forgot_penalty: self.get_appraisal_amount(cow_base_price, forgot_penalties),
+            event_reward: self.get_appraisal_amount(cow_base_price, event_bonus.max(0)),
+            event_penalty: self.get_appraisal_amount(cow_base_price, (-event_bonus).max(0)),
+            sickness_penalty,
+            is_penalty_capped,
+            payout,
+        }
+    }
+
+    fn get_appraisal_amount(&self, cow_base_price: Amount, multiplier: i128) -> Amount {
+        // multiplier is using 2 digit decimal precision, where 100% equivalent to 10_000
+        let amount = cow_base_price.saturating_mul(multiplier as u128);
+        Amount::from_tokens(amount.saturating_div(Amount::from_tokens(PRECISION_100_PERCENT as u128)))
+    }
+
+    pub async fn record_cow_bought(&mut self, price: Amount, is_replacing_dead_cow: bool) {
+        let mut stats = self.herd_stats.get().clone();
+        stats.total_bought += 1;
+        if is_replacing_dead_cow {
+            stats.total_died += 1;
+        }
+        self.herd_stats.set(stats);
+        self.record_treasury_inflow(price);
+    }
+
+    pub async fn record_cow_fed(&mut self, previous: FeedingStats, current: FeedingStats) {
+        let mut stats = self.herd_stats.get().clone();
+        stats.feeding_stats.on_time += current.on_time.saturating_sub(previous.on_time);
+        stats.feeding_stats.late += current.late.saturating_sub(previous.late);
+        stats.feeding_stats.forgot += current.forgot.saturating_sub(previous.forgot);
+        self.herd_stats.set(stats);
+    }
+
+    pub fn record_treasury_inflow(&mut self, amount: Amount) {
+        let mut stats = self.herd_stats.get().clone();
+        stats.treasury_inflow.saturating_add_assign(amount);
+        self.herd_stats.set(stats);
+    }
+
+    pub fn record_treasury_outflow(&mut self, amount: Amount) {
+        let mut stats = self.herd_stats.get().clone();
+        stats.treasury_outflow.saturating_add_assign(amount);
+        self.herd_stats.set(stats);
+    }
+
+    pub async fn record_cow_sold(&mut self, payment: Amount) {
+        let mut stats = self.herd_stats.get().clone();
+        stats.total_sold += 1;
+        self.herd_stats.set(stats);
+        self.record_treasury_outflow(payment);
+    }
+
+    pub fn record_cow_removed(&mut self) {
+        // kept apart from HerdStats, so the stored stats keep their layout
+        let total_removed = *self.total_removed.get() + 1;
+        self.total_removed.set(total_removed);
+    }
+
+    pub async fn get_herd_stats_report(&self, system_time: Timestamp) -> HerdStatsReport {
+        let stats = self.herd_stats.get().clone();
+
+        // count living cows by breed & gender, and dead cows that haven't been replaced yet
+        let mut living_cows: Vec<BreedPopulation> = CowBreed::ALL
+            .iter()
+            .map(|breed| BreedPopulation { breed: *breed, male: 0, female: 0 })
+            .collect();
+        let mut total_living = 0;
+        let mut total_died = stats.total_died;
+        let mut active_players = BTreeSet::new();
+        self.cow_db
+            .for_each_index_value(|_, cow| {
+                if self.get_cow_status(&cow, system_time) == FeedingStatus::Dead {
+                    total_died += 1;
+                    return Ok(());
+                }
+                let population = living_cows
+                    .iter_mut()
+                    .find(|population| population.breed == cow.breed)
+                    .expect("every breed has a population entry");
+                match cow.gender {
+                    CowGender::Male => population.male += 1,
+                    CowGender::Female => population.female += 1,
+                }
+                total_living += 1;
+                active_players.insert(cow.owner);
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cow db");
+            });
+
+        // average feeding quality is the share of ON TIME feeding
+        let feeding = &stats.feeding_stats;
+        let total_feeding = feeding.on_time + feeding.late + feeding.forgot;
+        let mut average_feeding_quality = 0;
+        if total_feeding != 0 {
+            average_feeding_quality = feeding.on_time * (PRECISION_100_PERCENT as u64) / total_feeding;
+        }
+
+        HerdStatsReport {
+            living_cows,
+            total_living,
+            total_bought: stats.total_bought,
+            total_sold: stats.total_sold,
+            total_died,
+            total_removed: *self.total_removed.get(),
+            feeding_stats: stats.feeding_stats,
+            average_feeding_quality,
+            treasury_inflow: stats.treasury_inflow,
+            treasury_outflow: stats.treasury_outflow,
+            active_players: active_players.len() as u64,
+        }
+    }
+
+    pub async fn get_player(&self, owner: Owner) -> Option<PlayerData> {
+        self.players.get(&owner).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Player Result");
+            })
+    }
+
+    pub async fn get_player_by_chain(&self, chain_id: ChainId) -> Option<PlayerData> {
+        let owner = self.player_chains.get(&chain_id).await
+            .unwrap_or_else(|_| {
+                panic!("unable to get Player Chain Result");
+            })?;
+        self.get_player(owner).await
+    }
+
+    pub async fn record_player_activity(&mut self, owner: Owner, chain_id: ChainId, system_time: Timestamp) {
+        // register new player on their first activity, cows bought before the registry existed are counted once
+        let mut player = match self.get_player(owner).await {
+            Some(player) => player,
+            None => PlayerData {
+                owner,
+                chain_id,
+                join_time: system_time,
+                last_activity: system_time,
+                herd_size: self.count_player_cows(owner).await,
+            },
+        };
+        // player moved to another chain, drop the old chain mapping
+        if player.chain_id != chain_id {
+            self.remove_player_chain(player.chain_id, owner).await;
+        }
+        player.chain_id = chain_id;
+        player.last_activity = system_time;
+        self.save_player(player).await;
+    }
+
+    pub async fn get_player_herd_size(&self, owner: Owner) -> Option<u64> {
+        self.get_player(owner).await.map(|player| player.herd_size)
+    }
+
+    pub async fn add_player_herd_size(&mut self, owner: Owner) {
+        if let Some(mut player) = self.get_player(owner).await {
+            player.herd_size = player.herd_size.saturating_add(1);
+            self.save_player(player).await;
+        }
+    }
+
+    pub async fn sub_player_herd_size(&mut self, owner: Owner) {
+        if let Some(mut player) = self.get_player(owner).await {
+            player.herd_size = player.herd_size.saturating_sub(1);
+            self.save_player(player).await;
+        }
+    }
+
+    pub async fn count_player_herd(&self, owner: Owner, system_time: Timestamp) -> u64 {
+        // dead cows are no longer part of the herd, even before they're bought back or removed
+        let mut herd_size = 0;
+        self.cow_db
+            .for_each_index_value(|_, cow| {
+                if cow.owner == owner && self.get_cow_status(&cow, system_time) != FeedingStatus::Dead {
+                    herd_size += 1;
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cow db");
+            });
+        herd_size
+    }
+
+    async fn count_player_cows(&self, owner: Owner) -> u64 {
+        // every cow the player holds, dead cows included
+        let mut cow_count = 0;
+        self.cow_db
+            .for_each_index_value(|_, cow| {
+                if cow.owner == owner {
+                    cow_count += 1;
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cow db");
+            });
+        cow_count
+    }
+
+    async fn remove_player_chain(&mut self, chain_id: ChainId, owner: Owner) {
+        // only drop the mapping when it still points to this player
+        if self.player_chains.get(&chain_id).await.ok().flatten() == Some(owner) {
+            self.player_chains
+                .remove(&chain_id)
+                .unwrap_or_else(|_| {
+                    panic!("Failed to remove Player Chain for {:?}", chain_id);
+                });
         }
-        cow_base_price.saturating_add(rewards_or_penalty)
     }
-}
\ No newline at end of file
+
+    async fn save_player(&mut self, player: PlayerData) {
+        self.player_chains
+            .insert(&player.chain_id, player.owner)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Player Chain for {:?}", player.chain_id);
+            });
+        self.players
+            .insert(&player.owner.clone(), player)
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Player Registry");
+            });
+    }
+}
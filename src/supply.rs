@@ -0,0 +1,135 @@
+use linera_sdk::base::Timestamp;
+use crate::{BreedSupply, BreedSupplyReport, CowBreed};
+use crate::state::MicroCow;
+
+/// [Supply]
+/// Breed population cap, and limited edition with a fixed total supply.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_breed_supplies(&self) -> Vec<BreedSupply> {
+        let breed_supplies = self.breed_supplies.get().clone();
+        if !breed_supplies.is_empty() {
+            return breed_supplies;
+        }
+
+        // every breed is unlimited, until admin set its supply
+        CowBreed::ALL
+            .iter()
+            .map(|breed| BreedSupply {
+                breed: *breed,
+                population_cap: None,
+                total_supply: None,
+                total_minted: 0,
+            })
+            .collect()
+    }
+
+    pub fn get_breed_supply(&self, breed: CowBreed) -> BreedSupply {
+        self.get_breed_supplies()
+            .into_iter()
+            .find(|supply| supply.breed == breed)
+            .unwrap_or_else(|| {
+                panic!("unable to get {:?} supply", breed);
+            })
+    }
+
+    pub fn set_population_cap(&mut self, breed: CowBreed, population_cap: Option<u64>) {
+        self.update_breed_supply(breed, |supply| {
+            supply.population_cap = population_cap;
+        });
+    }
+
+    pub fn release_limited_edition(&mut self, breed: CowBreed, total_supply: u64) {
+        // minted cows are counted from the release
+        self.update_breed_supply(breed, |supply| {
+            supply.total_supply = Some(total_supply);
+            supply.total_minted = 0;
+        });
+    }
+
+    pub fn record_breed_minted(&mut self, breed: CowBreed) {
+        self.update_breed_supply(breed, |supply| {
+            supply.total_minted += 1;
+        });
+    }
+
+    pub async fn get_breed_supply_report(&self, breed: CowBreed, system_time: Timestamp) -> BreedSupplyReport {
+        let supply = self.get_breed_supply(breed);
+        let living_cows = self.count_living_cows(breed, system_time).await;
+
+        // remaining cows is the lowest of population cap and limited edition supply
+        let remaining_population = supply.population_cap
+            .map(|population_cap| population_cap.saturating_sub(living_cows));
+        let remaining_supply = supply.total_supply
+            .map(|total_supply| total_supply.saturating_sub(supply.total_minted));
+        let remaining = match (remaining_population, remaining_supply) {
+            (Some(population), Some(supply)) => Some(population.min(supply)),
+            (population, supply) => population.or(supply),
+        };
+
+        BreedSupplyReport {
+            breed,
+            population_cap: supply.population_cap,
+            living_cows,
+            total_supply: supply.total_supply,
+            total_minted: supply.total_minted,
+            remaining,
+        }
+    }
+
+    pub async fn check_breed_supply(&self, breed: CowBreed, system_time: Timestamp) -> Result<(), String> {
+        let supply = self.get_breed_supply(breed);
+        let is_sold_out = supply.total_supply.is_some_and(|total_supply| supply.total_minted >= total_supply);
+        if is_sold_out {
+            return Err(format!("{:?} limited edition is sold out", breed));
+        }
+
+        // living cows are only counted for a breed with population cap
+        if let Some(population_cap) = supply.population_cap {
+            if self.count_living_cows(breed, system_time).await >= population_cap {
+                return Err(format!("{:?} population has reached its cap", breed));
+            }
+        }
+        Ok(())
+    }
+
+    fn update_breed_supply<F>(&mut self, breed: CowBreed, update: F)
+    where
+        F: FnOnce(&mut BreedSupply),
+    {
+        let mut breed_supplies = self.get_breed_supplies();
+        if let Some(supply) = breed_supplies.iter_mut().find(|supply| supply.breed == breed) {
+            update(supply);
+        }
+        self.breed_supplies.set(breed_supplies);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::Timestamp;
+    use linera_sdk::views::KeyValueStore;
+    use crate::CowBreed;
+    use crate::test_utils::load_state;
+
+    #[test]
+    fn limited_edition_sells_out() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = load_state(&store).await;
+            let system_time = Timestamp::from(1_000);
+            state.release_limited_edition(CowBreed::Holstein, 2);
+            state.record_breed_minted(CowBreed::Holstein);
+
+            let report = state.get_breed_supply_report(CowBreed::Holstein, system_time).await;
+            assert_eq!(report.remaining, Some(1));
+            assert!(state.check_breed_supply(CowBreed::Holstein, system_time).await.is_ok());
+
+            state.record_breed_minted(CowBreed::Holstein);
+            let reason = state.check_breed_supply(CowBreed::Holstein, system_time).await.unwrap_err();
+            assert_eq!(reason, "Holstein limited edition is sold out");
+            assert_eq!(state.get_breed_supply_report(CowBreed::Jersey, system_time).await.remaining, None);
+        });
+    }
+}
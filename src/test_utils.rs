@@ -0,0 +1,28 @@
+use std::str::FromStr;
+use linera_sdk::base::{Owner, Timestamp};
+use linera_sdk::views::{KeyValueStore, View, ViewStorageContext};
+use crate::{CowBreed, CowData, CowGender, FeedingStats};
+use crate::state::MicroCow;
+
+/// Owner with every key byte set to `byte`, e.g. `test_owner("01")`.
+pub fn test_owner(byte: &str) -> Owner {
+    Owner::from_str(&byte.repeat(32)).unwrap()
+}
+
+/// Female Jersey born at the start of time, last fed at `last_fed_time`.
+pub fn test_cow(name: &str, owner: Owner, last_fed_time: u64) -> CowData {
+    CowData::new(
+        String::from("cow-id"),
+        String::from(name),
+        CowBreed::Jersey,
+        CowGender::Female,
+        Timestamp::from(0),
+        Timestamp::from(last_fed_time),
+        FeedingStats::new(),
+        owner,
+    )
+}
+
+pub async fn load_state(store: &KeyValueStore) -> MicroCow {
+    MicroCow::load(ViewStorageContext::from(store.to_mut())).await.unwrap()
+}
@@ -0,0 +1,153 @@
+use linera_sdk::base::{Amount, Timestamp};
+use crate::{CowData, FeedingStatus, LiabilityCache, TreasuryHealth};
+use crate::constants::*;
+use crate::state::MicroCow;
+
+/// [Treasury]
+/// Reserve policy of the root chain balance against the appraisal price of all living cows.
+/// ------------------------------------------------------------------------------------------
+impl MicroCow {
+    pub fn get_min_reserve_ratio(&self) -> u64 {
+        self.min_reserve_ratio.get().unwrap_or(MIN_RESERVE_RATIO)
+    }
+
+    pub fn set_min_reserve_ratio(&mut self, min_reserve_ratio: u64) {
+        // zero ratio would scale every payout by a division by zero
+        if min_reserve_ratio == 0 || min_reserve_ratio > MAX_MIN_RESERVE_RATIO {
+            panic!("min reserve ratio must be between 1 and {}", MAX_MIN_RESERVE_RATIO);
+        }
+        self.min_reserve_ratio.set(Some(min_reserve_ratio));
+    }
+
+    pub async fn get_total_liability(&self, system_time: Timestamp) -> Amount {
+        // collect all living cows
+        let mut living_cows: Vec<CowData> = Vec::new();
+        self.cow_db
+            .for_each_index_value(|_, cow| {
+                if self.get_cow_status(&cow, system_time) != FeedingStatus::Dead {
+                    living_cows.push(cow);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or_else(|_| {
+                panic!("unable to read cow db");
+            });
+
+        // sum the appraisal price of every living cow
+        let mut total_liability = Amount::ZERO;
+        for cow in living_cows.into_iter() {
+            total_liability.saturating_add_assign(self.cow_sell_value(cow, system_time).await);
+        }
+        total_liability
+    }
+
+    pub async fn get_treasury_health(&self, system_time: Timestamp) -> TreasuryHealth {
+        let total_liability = self.get_total_liability(system_time).await;
+        self.build_treasury_health(total_liability)
+    }
+
+    pub async fn get_block_treasury_health(&mut self, system_time: Timestamp) -> TreasuryHealth {
+        // appraising every cow is expensive, so every payout in the same block shares one total liability
+        let cached_liability = self.liability_cache.get().clone()
+            .filter(|cache| cache.computed_at == system_time)
+            .map(|cache| cache.total_liability);
+        let total_liability = match cached_liability {
+            Some(total_liability) => total_liability,
+            None => {
+                let total_liability = self.get_total_liability(system_time).await;
+                self.liability_cache.set(Some(LiabilityCache { computed_at: system_time, total_liability }));
+                total_liability
+            }
+        };
+        self.build_treasury_health(total_liability)
+    }
+
+    fn build_treasury_health(&self, total_liability: Amount) -> TreasuryHealth {
+        let balance = self.app_data.get().balance;
+        let min_reserve_ratio = self.get_min_reserve_ratio();
+
+        // reserve ratio is unlimited when there is no liability
+        let reserve_ratio = balance
+            .saturating_mul(PRECISION_100_PERCENT as u128)
+            .saturating_div(total_liability)
+            .min(u64::MAX as u128) as u64;
+
+        // scale payout down with the same proportion as the reserve shortage
+        let is_healthy = reserve_ratio >= min_reserve_ratio;
+        let mut payout_scale = PRECISION_100_PERCENT as u64;
+        if !is_healthy {
+            payout_scale = (reserve_ratio as u128 * PRECISION_100_PERCENT as u128 / min_reserve_ratio.max(1) as u128) as u64;
+        }
+
+        TreasuryHealth {
+            balance,
+            total_liability,
+            reserve_ratio,
+            min_reserve_ratio,
+            is_healthy,
+            payout_scale,
+        }
+    }
+
+    pub fn scale_payout(&self, payout: Amount, treasury_health: &TreasuryHealth) -> Amount {
+        if treasury_health.is_healthy {
+            return payout;
+        }
+        let scaled_payout = payout
+            .saturating_mul(treasury_health.payout_scale as u128)
+            .saturating_div(Amount::from_tokens(PRECISION_100_PERCENT as u128));
+        Amount::from_tokens(scaled_payout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use linera_sdk::base::{Amount, ChainId, Timestamp};
+    use linera_sdk::views::KeyValueStore;
+    use crate::constants::*;
+    use crate::state::MicroCow;
+    use crate::test_utils::{load_state, test_cow, test_owner};
+
+    const START_TIME: u64 = 10 * UNIX_MICROS_IN_24_HOURS;
+
+    async fn root_state(store: &KeyValueStore, balance: u128) -> MicroCow {
+        let mut state = load_state(store).await;
+        state.initialize(test_owner("ad"), ChainId::root(0), Amount::from_tokens(balance), true).await;
+        for (name, last_fed_time) in [("Bessie", START_TIME), ("Daisy", START_TIME), ("Ghost", 0)] {
+            state.create_new_or_update_cow_data(String::from(name), test_cow(name, test_owner("01"), last_fed_time)).await;
+        }
+        state
+    }
+
+    #[test]
+    fn liability_counts_living_cows_only() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let state = root_state(&store, 5_000).await;
+            let treasury_health = state.get_treasury_health(Timestamp::from(START_TIME)).await;
+            assert_eq!(treasury_health.total_liability, Amount::from_tokens(2 * JERSEY_PRICE));
+            assert_eq!(treasury_health.reserve_ratio, 25_000);
+            assert!(treasury_health.is_healthy);
+
+            let payout = Amount::from_tokens(JERSEY_PRICE);
+            assert_eq!(state.scale_payout(payout, &treasury_health), payout);
+        });
+    }
+
+    #[test]
+    fn reserve_shortage_scales_payouts() {
+        block_on(async {
+            let store = KeyValueStore::mock();
+            let mut state = root_state(&store, 500).await;
+            state.min_reserve_ratio.set(Some(5_000));
+
+            let treasury_health = state.get_treasury_health(Timestamp::from(START_TIME)).await;
+            assert_eq!(treasury_health.reserve_ratio, 2_500);
+            assert!(!treasury_health.is_healthy);
+            assert_eq!(treasury_health.payout_scale, 5_000);
+            assert_eq!(state.scale_payout(Amount::from_tokens(JERSEY_PRICE), &treasury_health), Amount::from_tokens(500));
+        });
+    }
+}